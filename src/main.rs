@@ -1,7 +1,68 @@
-use wordle::wordle_game::game_objects::Game;
+use std::env;
+use std::io::{stdin, stdout, BufReader};
+use std::process;
+use std::sync::{Arc, Mutex};
+use wordle::wordle_game::game_objects::{Game, GameSummary};
+use wordle::wordle_game::session::play_again;
+use wordle::wordle_game::stats::Stats;
+
+const STATS_FILE: &str = "wordle-stats.json";
 
 fn main() {
-    let mut new_game = Game::default();
-    let game_result = new_game.run();
-    println!("{:?}", game_result);
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let again = args.iter().any(|a| a == "--play-again");
+    args.retain(|a| a != "--play-again");
+    let mut first_game = match Game::from_args(&args) {
+        Ok(game) => Some(game),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let mut next_game = || {
+        first_game
+            .take()
+            .unwrap_or_else(|| Game::from_args(&args).expect("arguments were already validated"))
+    };
+    let stats = Arc::new(Mutex::new(Stats::load_or_default(STATS_FILE)));
+    #[cfg(feature = "ctrlc")]
+    save_stats_on_interrupt(Arc::clone(&stats));
+    let on_round = |game_result: &GameSummary| {
+        let mut stats = stats.lock().expect("stats lock poisoned");
+        stats.record(game_result);
+        if let Err(e) = stats.save(STATS_FILE) {
+            eprintln!("failed to save stats: {}", e);
+        }
+        println!("{:?}", game_result);
+        println!(
+            "Alphabet coverage: {:.0}%",
+            game_result.alphabet_coverage() * 100.0
+        );
+    };
+    if again {
+        // Read the answers a byte at a time so nothing past the newline is
+        // buffered away from the next round's player.
+        play_again(
+            next_game,
+            &mut BufReader::with_capacity(1, stdin()),
+            &mut stdout(),
+            on_round,
+        )
+        .expect("failed to write the game to stdout");
+    } else {
+        on_round(&next_game().run());
+    }
+}
+
+#[cfg(feature = "ctrlc")]
+fn save_stats_on_interrupt(stats: Arc<Mutex<Stats>>) {
+    ctrlc::set_handler(move || {
+        if let Ok(stats) = stats.lock() {
+            if let Err(e) = stats.save(STATS_FILE) {
+                eprintln!("failed to save stats: {}", e);
+            }
+        }
+        process::exit(130);
+    })
+    .expect("failed to install the Ctrl-C handler");
 }
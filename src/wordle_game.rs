@@ -1,3 +1,32 @@
 pub mod consts;
 
+pub mod errors;
+
 pub mod game_objects;
+
+pub mod word_list;
+
+pub mod render;
+
+pub mod session;
+
+#[cfg(feature = "network")]
+pub mod network;
+
+pub mod adversarial;
+
+pub mod solver;
+
+pub mod turn_based;
+
+pub mod multi;
+
+pub mod reverse;
+
+pub mod replay;
+
+pub mod batch;
+
+pub mod stats;
+
+pub mod util;
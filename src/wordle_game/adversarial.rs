@@ -0,0 +1,205 @@
+use super::errors::GameError;
+use super::game_objects::{check_guess_shape, score_guess, Board, GameSummary, Player, Word};
+use super::render::Renderer;
+use super::word_list::WordList;
+use colored::Colorize;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, RngCore};
+use std::collections::BTreeMap;
+use std::io::{self, stdout, Write};
+use std::time::Instant;
+
+pub struct AdversarialGame<P: Player> {
+    candidates: Vec<String>,
+    number_of_attempts: u8,
+    attempts_left: u8,
+    board: Board,
+    player: P,
+    renderer: Renderer,
+    rng: Box<dyn RngCore>,
+}
+
+impl<P: Player> AdversarialGame<P> {
+    pub fn new(
+        word_list: &WordList,
+        word_length: u8,
+        number_of_attempts: u8,
+        player: P,
+    ) -> Result<Self, GameError> {
+        let candidates: Vec<String> = word_list
+            .words()
+            .iter()
+            .filter(|w| w.chars().count() == word_length as usize)
+            .cloned()
+            .collect();
+        if candidates.is_empty() {
+            return Err(GameError::EmptyWord);
+        }
+        Ok(Self {
+            candidates,
+            number_of_attempts,
+            attempts_left: number_of_attempts,
+            board: Board::new(word_length, number_of_attempts),
+            player,
+            renderer: Renderer::default(),
+            rng: Box::new(thread_rng()),
+        })
+    }
+
+    pub fn with_rng(mut self, rng: Box<dyn RngCore>) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    pub fn candidates(&self) -> &[String] {
+        &self.candidates
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    fn choose_feedback(&mut self, guess: &str) -> Word {
+        let mut buckets: BTreeMap<String, (Word, Vec<String>)> = BTreeMap::new();
+        for candidate in self.candidates.drain(..) {
+            let feedback = score_guess(&candidate, guess);
+            buckets
+                .entry(feedback.pattern())
+                .or_insert_with(|| (feedback, vec![]))
+                .1
+                .push(candidate);
+        }
+        let largest = buckets
+            .values()
+            .map(|(feedback, remaining)| (remaining.len(), !feedback.is_solved()))
+            .max()
+            .expect("adversarial game has no candidates left");
+        let tied: Vec<(Word, Vec<String>)> = buckets
+            .into_values()
+            .filter(|(feedback, remaining)| (remaining.len(), !feedback.is_solved()) == largest)
+            .collect();
+        let (feedback, remaining) = tied
+            .choose(&mut self.rng)
+            .cloned()
+            .expect("adversarial game has no candidates left");
+        self.candidates = remaining;
+        feedback
+    }
+
+    pub fn run(&mut self) -> GameSummary {
+        self.run_with_output(&mut stdout())
+            .expect("failed to write the game to stdout")
+    }
+
+    pub fn run_with_output<W: Write>(&mut self, out: &mut W) -> io::Result<GameSummary> {
+        self.board.write_to(&self.renderer, out)?;
+        let mut guess_times = vec![];
+        let mut won = false;
+        while self.attempts_left > 0 {
+            let started = Instant::now();
+            let guess = loop {
+                if let Some(prompt) = self.player.prompt() {
+                    writeln!(out, "{}", prompt)?;
+                }
+                let Some(guess) = self.player.get_play(&self.board) else {
+                    break None;
+                };
+                let guess = guess.to_lowercase();
+                let width = self.board.width();
+                if let Err(e) = check_guess_shape(&guess, width, self.player.length_mode()) {
+                    writeln!(out, "{}", format!("{}, try again", e).yellow())?;
+                    continue;
+                }
+                self.player.guess_accepted();
+                break Some(guess);
+            };
+            let Some(guess) = guess else {
+                break;
+            };
+            guess_times.push(started.elapsed());
+            let feedback = self.choose_feedback(&guess);
+            won = feedback.is_solved();
+            let index = (self.number_of_attempts - self.attempts_left) as usize;
+            self.board.add_word(feedback, index);
+            debug_assert!(
+                self.candidates.iter().all(|c| self.board.is_consistent(c)),
+                "adversarial feedback must match every remaining candidate"
+            );
+            self.board.write_to(&self.renderer, out)?;
+            self.attempts_left -= 1;
+            if won {
+                break;
+            }
+        }
+        Ok(GameSummary::from_board(
+            won,
+            guess_times,
+            &self.board,
+            &self.candidates[0],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::ScriptedPlayer;
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn adversary_always_keeps_an_answer() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate", "trace"]
+            .into_iter()
+            .collect();
+        let player = ScriptedPlayer::new(&["crane", "plate", "angle", "ample"]);
+        let mut game = AdversarialGame::new(&list, 5, 4, player).unwrap();
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(!summary.won);
+        assert!(!game.candidates().is_empty());
+        assert!(game
+            .candidates()
+            .iter()
+            .all(|c| game.board().is_consistent(c)));
+    }
+
+    #[test]
+    fn adversary_concedes_when_one_candidate_remains() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = AdversarialGame::new(&list, 5, 4, player).unwrap();
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 2);
+        assert_eq!(game.candidates(), ["apple"]);
+    }
+
+    #[test]
+    fn malformed_guesses_are_rejected() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        let player = ScriptedPlayer::new(&["", "app", "cr4ne", "crane", "apple"]);
+        let mut game = AdversarialGame::new(&list, 5, 4, player).unwrap();
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert!(summary.won);
+        assert_eq!(summary.guesses, ["crane", "apple"]);
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("try again").count(), 3);
+    }
+
+    #[test]
+    fn seeded_tie_breaking_is_reproducible() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate", "trace"]
+            .into_iter()
+            .collect();
+        let play = |seed| {
+            let player = ScriptedPlayer::new(&["fuzzy", "crane"]);
+            let mut game = AdversarialGame::new(&list, 5, 2, player)
+                .unwrap()
+                .with_rng(Box::new(StdRng::seed_from_u64(seed)));
+            game.run_with_output(&mut vec![]).unwrap();
+            game.candidates().to_vec()
+        };
+        assert_eq!(play(11), play(11));
+    }
+}
@@ -0,0 +1,150 @@
+use super::game_objects::{Game, GameSummary, Player};
+use super::word_list::WordList;
+use rand::RngCore;
+use std::io::sink;
+
+pub type PlayerFactory = Box<dyn Fn() -> Box<dyn Player>>;
+
+pub fn run_batch<P, F>(targets: &[String], attempts: u8, mut solver_factory: F) -> Vec<GameSummary>
+where
+    P: Player,
+    F: FnMut() -> P,
+{
+    targets
+        .iter()
+        .map(|target| {
+            let mut game = Game::new(target.clone(), attempts, solver_factory());
+            game.run_with_output(&mut sink())
+                .expect("writing to a sink cannot fail")
+        })
+        .collect()
+}
+
+pub fn solvable_within<P, F>(
+    word_list: &WordList,
+    solver_factory: F,
+    attempts: u8,
+    n: usize,
+) -> Vec<String>
+where
+    P: Player,
+    F: FnMut() -> P,
+{
+    run_batch(word_list.words(), attempts, solver_factory)
+        .into_iter()
+        .filter(|summary| summary.won && summary.attempts_used <= n)
+        .map(|summary| summary.target)
+        .collect()
+}
+
+pub fn hardest_words<P, F>(
+    word_list: &WordList,
+    solver_factory: F,
+    attempts: u8,
+    top_n: usize,
+) -> Vec<(String, u8)>
+where
+    P: Player,
+    F: FnMut() -> P,
+{
+    let mut results: Vec<(String, u8)> = run_batch(word_list.words(), attempts, solver_factory)
+        .into_iter()
+        .map(|summary| {
+            let guesses = if summary.won {
+                summary.attempts_used as u8
+            } else {
+                attempts.saturating_add(1)
+            };
+            (summary.target, guesses)
+        })
+        .collect();
+    results.sort_by_key(|(_, guesses)| std::cmp::Reverse(*guesses));
+    results.truncate(top_n);
+    results
+}
+
+/// Every player plays the list's words in the same order, shuffled by `rng`.
+pub fn tournament(
+    players: Vec<(String, PlayerFactory)>,
+    word_list: &WordList,
+    attempts: u8,
+    rng: &mut dyn RngCore,
+) -> Vec<(String, f64)> {
+    let targets = word_list.shuffled(rng);
+    let mut standings: Vec<(String, f64)> = players
+        .into_iter()
+        .map(|(name, factory)| {
+            let solved: Vec<usize> = run_batch(&targets, attempts, factory)
+                .into_iter()
+                .filter(|summary| summary.won)
+                .map(|summary| summary.attempts_used)
+                .collect();
+            let average = if solved.is_empty() {
+                f64::INFINITY
+            } else {
+                solved.iter().sum::<usize>() as f64 / solved.len() as f64
+            };
+            (name, average)
+        })
+        .collect();
+    standings.sort_by(|a, b| a.1.total_cmp(&b.1));
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::ScriptedPlayer;
+    use super::super::solver::MinimaxPlayer;
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn solvable_within_filters_by_guess_count() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let solver = || MinimaxPlayer::new(list.clone());
+        let all = solvable_within(&list, solver, 6, 6);
+        assert_eq!(all, list.words());
+        let first_guess = solvable_within(&list, solver, 6, 1);
+        assert_eq!(first_guess.len(), 1);
+        assert!(list.contains(&first_guess[0]));
+    }
+
+    #[test]
+    fn hardest_words_rank_losses_first() {
+        let words = ["apple", "ample", "angle", "crane", "plate"];
+        let list: WordList = words.into_iter().collect();
+        let hardest = hardest_words(&list, || ScriptedPlayer::new(&words), 3, 3);
+        assert_eq!(
+            hardest,
+            [
+                ("crane".to_owned(), 4),
+                ("plate".to_owned(), 4),
+                ("angle".to_owned(), 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn tournament_ranks_by_average_guesses() {
+        let words = ["apple", "ample", "angle", "crane", "plate"];
+        let list: WordList = words.into_iter().collect();
+        let solver_list = list.clone();
+        let players: Vec<(String, PlayerFactory)> = vec![
+            (
+                "in order".to_owned(),
+                Box::new(move || Box::new(ScriptedPlayer::new(&words))),
+            ),
+            (
+                "minimax".to_owned(),
+                Box::new(move || Box::new(MinimaxPlayer::new(solver_list.clone()))),
+            ),
+        ];
+        let standings = tournament(players, &list, 6, &mut StdRng::seed_from_u64(3));
+        assert_eq!(standings[0].0, "minimax");
+        assert_eq!(standings[1], ("in order".to_owned(), 3.0));
+        assert!(standings[0].1 < standings[1].1);
+    }
+}
@@ -0,0 +1,77 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum GameError {
+    EmptyWord,
+    InvalidCharacter(char),
+    NotInWordList(String),
+    LengthMismatch { expected: usize, found: usize },
+    TargetUnreachable(String),
+    Network(String),
+    InvalidArgument(String),
+    WordListFile(String),
+    Output(String),
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyWord => write!(f, "the target word is empty"),
+            Self::InvalidCharacter(c) => write!(f, "'{}' is not part of the alphabet", c),
+            Self::NotInWordList(w) => write!(f, "'{}' is not in the word list", w),
+            Self::LengthMismatch { expected, found } => write!(
+                f,
+                "expected a word of length {}, found length {}",
+                expected, found
+            ),
+            Self::TargetUnreachable(w) => {
+                write!(f, "'{}' can never be guessed with this word list", w)
+            }
+            Self::Network(e) => write!(f, "could not fetch the target word: {}", e),
+            Self::InvalidArgument(a) => write!(f, "invalid argument: {}", a),
+            Self::WordListFile(e) => write!(f, "could not read the word list: {}", e),
+            Self::Output(e) => write!(f, "could not write the game: {}", e),
+        }
+    }
+}
+
+impl Error for GameError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum InputError {
+    WrongLength { expected: usize, found: usize },
+    InvalidCharacter(char),
+    NotInWordList(String),
+    ViolatesClues(String),
+    TooManyRepeats { letter: char, max: usize },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongLength { expected, found } => {
+                write!(f, "guesses must have {} letters, found {}", expected, found)
+            }
+            Self::InvalidCharacter(c) => write!(f, "'{}' is not part of the alphabet", c),
+            Self::NotInWordList(w) => write!(f, "'{}' is not in the word list", w),
+            Self::ViolatesClues(w) => write!(f, "'{}' can't be the answer based on the clues", w),
+            Self::TooManyRepeats { letter, max } => {
+                write!(f, "'{}' can't appear more than {} times", letter, max)
+            }
+        }
+    }
+}
+
+impl Error for InputError {}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseSlotStateError(pub String);
+
+impl fmt::Display for ParseSlotStateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' is not a valid slot state", self.0)
+    }
+}
+
+impl Error for ParseSlotStateError {}
@@ -1,43 +1,513 @@
-use colored::{ColoredString, Colorize};
-use std::io::stdin;
+use super::consts::{ALPHABET, DEFAULT_ATTEMPTS, MAX_MULLIGANS, MULLIGAN_COMMAND, WORDS};
+use super::errors::{GameError, InputError, ParseSlotStateError};
+use super::render::{
+    print_legend, print_template, template, to_rgb, Orientation, Renderer, RevealOrder, Theme,
+};
+use super::solver::{expected_attempts, grade_guess};
+use super::util::{keyboard_adjacent, levenshtein, word_similarity};
+use super::word_list::WordList;
+use colored::{Color, ColoredString, Colorize};
+use rand::seq::SliceRandom;
+use rand::{thread_rng, RngCore};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt;
+use std::io::{self, stdin, stdout, BufRead, Write};
+use std::str::FromStr;
+use std::sync::mpsc::Sender;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const PLACEHOLDER: char = '_';
+
+pub type GuessTransform = Box<dyn Fn(&str) -> String>;
 
 pub struct Game<P: Player> {
     word: String,
+    alternate_answers: Vec<String>,
     number_of_attempts: u8,
     attempts_left: u8,
     board: Board,
     player: P,
+    starting_hint: bool,
+    show_legend: bool,
+    verbose: bool,
+    show_attempts_remaining: bool,
+    show_hearts: bool,
+    hard_mode: bool,
+    hint_budget: u8,
+    hints_left: u8,
+    max_letter_repeats: Option<usize>,
+    warn_impossible: bool,
+    coach: bool,
+    show_attempt_estimate: bool,
+    show_eliminations: bool,
+    near_miss_hints: bool,
+    allow_mulligan: bool,
+    mulligans_used: u8,
+    all_gray_penalty: u32,
+    score: i32,
+    word_list: Option<WordList>,
+    progress_callback: Option<Box<dyn FnMut(usize)>>,
+    spectator: Option<Sender<BoardSnapshot>>,
+    common_word_rank: Option<u32>,
+    guess_transform: Option<GuessTransform>,
+    renderer: Renderer,
+    metadata: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FeedbackMode {
+    #[default]
+    Full,
+    GreensOnly,
+}
+
+impl FeedbackMode {
+    pub fn apply(&self, word: Word) -> Word {
+        match (self, word) {
+            (FeedbackMode::GreensOnly, Word::Full(slots)) => Word::Full(
+                slots
+                    .into_iter()
+                    .map(|ss| match ss {
+                        SlotState::PartialMatch(c) => SlotState::NonMatch(c),
+                        other => other,
+                    })
+                    .collect(),
+            ),
+            (_, word) => word,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+impl Difficulty {
+    pub fn attempts(&self) -> u8 {
+        match self {
+            Difficulty::Easy => DEFAULT_ATTEMPTS + 2,
+            Difficulty::Normal | Difficulty::Hard => DEFAULT_ATTEMPTS,
+        }
+    }
+
+    pub fn hint_budget(&self) -> u8 {
+        match self {
+            Difficulty::Easy => 3,
+            Difficulty::Normal => 1,
+            Difficulty::Hard => 0,
+        }
+    }
+
+    pub fn hard_mode(&self) -> bool {
+        *self == Difficulty::Hard
+    }
 }
 
 impl<P: Player> Game<P> {
     pub fn new(word: String, number_of_attempts: u8, player: P) -> Self {
+        if let Some(word_length) = player.word_length() {
+            assert_eq!(
+                word_length as usize,
+                word.chars().count(),
+                "player word length does not match the target word"
+            );
+        }
         Self {
-            board: Board::new(word.len() as u8, number_of_attempts),
+            board: Board::new(word.chars().count() as u8, number_of_attempts),
             word: word.to_lowercase(),
+            alternate_answers: vec![],
             number_of_attempts,
             attempts_left: number_of_attempts,
-            player: player,
+            player,
+            starting_hint: false,
+            show_legend: false,
+            verbose: false,
+            show_attempts_remaining: false,
+            show_hearts: false,
+            hard_mode: false,
+            hint_budget: 0,
+            hints_left: 0,
+            max_letter_repeats: None,
+            warn_impossible: false,
+            coach: false,
+            show_attempt_estimate: false,
+            show_eliminations: false,
+            near_miss_hints: false,
+            allow_mulligan: false,
+            mulligans_used: 0,
+            all_gray_penalty: 0,
+            score: 0,
+            word_list: None,
+            progress_callback: None,
+            spectator: None,
+            common_word_rank: None,
+            guess_transform: None,
+            renderer: Renderer::default(),
+            metadata: HashMap::new(),
+        }
+    }
+
+    pub fn with_answers(answers: Vec<String>, number_of_attempts: u8, player: P) -> Self {
+        let mut answers = answers.into_iter().map(|a| a.to_lowercase());
+        let word = answers.next().expect("at least one answer is required");
+        let alternate_answers: Vec<String> = answers.collect();
+        assert!(
+            alternate_answers
+                .iter()
+                .all(|a| a.chars().count() == word.chars().count()),
+            "all answers must have the same length"
+        );
+        let mut game = Self::new(word, number_of_attempts, player);
+        game.alternate_answers = alternate_answers;
+        game
+    }
+
+    pub fn try_new(
+        word: String,
+        number_of_attempts: u8,
+        player: P,
+        word_list: Option<WordList>,
+    ) -> Result<Self, GameError> {
+        match &word_list {
+            Some(word_list) => is_valid_target(&word, word_list).map_err(|e| match e {
+                GameError::NotInWordList(w) => GameError::TargetUnreachable(w),
+                e => e,
+            })?,
+            None => check_target_chars(&word)?,
+        }
+        check_player_length(&word, &player)?;
+        let game = Self::new(word, number_of_attempts, player);
+        Ok(match word_list {
+            Some(word_list) => game.with_word_list(word_list),
+            None => game,
+        })
+    }
+
+    pub fn target(&self) -> &str {
+        &self.word
+    }
+
+    pub fn target_hash(&self) -> u64 {
+        fnv1a(self.word.to_lowercase().as_bytes())
+    }
+
+    pub fn player(&self) -> &P {
+        &self.player
+    }
+
+    pub fn with_starting_hint(mut self, starting_hint: bool) -> Self {
+        self.starting_hint = starting_hint;
+        self.apply_starting_hint();
+        self
+    }
+
+    pub fn with_legend(mut self, show_legend: bool) -> Self {
+        self.show_legend = show_legend;
+        self
+    }
+
+    pub fn with_verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    pub fn with_attempts_remaining(mut self, show_attempts_remaining: bool) -> Self {
+        self.show_attempts_remaining = show_attempts_remaining;
+        self
+    }
+
+    pub fn with_hearts(mut self, show_hearts: bool) -> Self {
+        self.show_hearts = show_hearts;
+        self
+    }
+
+    pub fn with_hard_mode(mut self, hard_mode: bool) -> Self {
+        self.hard_mode = hard_mode;
+        self
+    }
+
+    pub fn with_hint_budget(mut self, hint_budget: u8) -> Self {
+        self.hint_budget = hint_budget;
+        self.hints_left = hint_budget;
+        self
+    }
+
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.number_of_attempts = difficulty.attempts();
+        self.attempts_left = self.number_of_attempts;
+        let empty = Word::Empty(self.board.width);
+        self.board
+            .words
+            .resize(self.number_of_attempts as usize, empty);
+        self.with_hint_budget(difficulty.hint_budget())
+            .with_hard_mode(difficulty.hard_mode())
+    }
+
+    pub fn hints_left(&self) -> u8 {
+        self.hints_left
+    }
+
+    pub fn number_of_attempts(&self) -> u8 {
+        self.number_of_attempts
+    }
+
+    pub fn use_hint(&mut self) -> Option<(usize, char)> {
+        if self.hints_left == 0 {
+            return None;
+        }
+        let known = self.board.correct_positions();
+        let hint = self.word.chars().enumerate().find(|(i, _)| {
+            !known
+                .iter()
+                .chain(&self.board.hints)
+                .any(|(pos, _)| pos == i)
+        })?;
+        self.board.hints.push(hint);
+        self.hints_left -= 1;
+        Some(hint)
+    }
+
+    pub fn with_max_letter_repeats(mut self, max_letter_repeats: usize) -> Self {
+        self.max_letter_repeats = Some(max_letter_repeats);
+        self
+    }
+
+    fn excess_repeat(&self, guess: &str) -> Option<(char, usize)> {
+        let max = self.max_letter_repeats?;
+        guess
+            .chars()
+            .find(|c| guess.chars().filter(|other| other == c).count() > max)
+            .map(|c| (c, max))
+    }
+
+    pub fn with_gray_meaning(mut self, gray_meaning: GrayMeaning) -> Self {
+        self.board.set_gray_meaning(gray_meaning);
+        self
+    }
+
+    pub fn with_impossible_guess_warning(mut self, warn_impossible: bool) -> Self {
+        self.warn_impossible = warn_impossible;
+        self
+    }
+
+    pub fn with_word_list(mut self, word_list: WordList) -> Self {
+        self.word_list = Some(word_list);
+        self
+    }
+
+    pub fn with_progress_callback(mut self, callback: Box<dyn FnMut(usize)>) -> Self {
+        self.progress_callback = Some(callback);
+        self
+    }
+
+    pub fn with_common_word_countdown(mut self, max_rank: u32) -> Self {
+        self.common_word_rank = Some(max_rank);
+        self
+    }
+
+    pub fn with_spectator(mut self, spectator: Sender<BoardSnapshot>) -> Self {
+        self.spectator = Some(spectator);
+        self
+    }
+
+    pub fn with_guess_transform(mut self, transform: GuessTransform) -> Self {
+        self.guess_transform = Some(transform);
+        self
+    }
+
+    pub fn with_coach(mut self, coach: bool) -> Self {
+        self.coach = coach;
+        self
+    }
+
+    pub fn with_attempt_estimate(mut self, show_attempt_estimate: bool) -> Self {
+        self.show_attempt_estimate = show_attempt_estimate;
+        self
+    }
+
+    pub fn with_eliminations(mut self, show_eliminations: bool) -> Self {
+        self.show_eliminations = show_eliminations;
+        self
+    }
+
+    pub fn with_near_miss_hints(mut self, near_miss_hints: bool) -> Self {
+        self.near_miss_hints = near_miss_hints;
+        self
+    }
+
+    pub fn with_mulligan(mut self, allow_mulligan: bool) -> Self {
+        self.allow_mulligan = allow_mulligan;
+        self
+    }
+
+    pub fn mulligans_used(&self) -> u8 {
+        self.mulligans_used
+    }
+
+    pub fn with_all_gray_penalty(mut self, all_gray_penalty: u32) -> Self {
+        self.all_gray_penalty = all_gray_penalty;
+        self
+    }
+
+    pub fn set_meta(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.metadata.insert(key.into(), value.into());
+    }
+
+    pub fn score(&self) -> i32 {
+        self.score
+    }
+
+    pub fn with_feedback_mode(mut self, feedback_mode: FeedbackMode) -> Self {
+        self.board.set_feedback_mode(feedback_mode);
+        self
+    }
+
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.renderer.theme = theme;
+        self
+    }
+
+    pub fn with_renderer(mut self, renderer: Renderer) -> Self {
+        self.renderer = renderer;
+        self
+    }
+
+    pub fn reset(&mut self, new_word: String) -> Result<(), GameError> {
+        let found = new_word.chars().count();
+        if found != self.board.width as usize {
+            return Err(GameError::LengthMismatch {
+                expected: self.board.width as usize,
+                found,
+            });
+        }
+        self.word = new_word.to_lowercase();
+        self.alternate_answers.clear();
+        self.attempts_left = self.number_of_attempts;
+        self.hints_left = self.hint_budget;
+        self.mulligans_used = 0;
+        self.score = 0;
+        self.board.clear();
+        self.apply_starting_hint();
+        Ok(())
+    }
+
+    fn apply_starting_hint(&mut self) {
+        self.board.hints.clear();
+        if self.starting_hint {
+            if let Some(first) = self.word.chars().next() {
+                self.board.hints.push((0, first));
+            }
         }
     }
 }
 
 impl<P: Player> Game<P> {
+    fn answers(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.word).chain(&self.alternate_answers)
+    }
+
+    fn is_answer(&self, guess: &str) -> bool {
+        self.answers().any(|a| a == guess)
+    }
+
+    fn closest_answer(&self, guess: &str) -> &str {
+        let mut closest = &self.word;
+        let mut most_greens = 0;
+        for answer in self.answers() {
+            let greens = guess
+                .chars()
+                .zip(answer.chars())
+                .filter(|(g, a)| g == a)
+                .count();
+            if greens > most_greens {
+                closest = answer;
+                most_greens = greens;
+            }
+        }
+        closest
+    }
+
     fn get_diff(&self, player_word: &str) -> Word {
-        let mut slots = vec![];
-        for i in 0..self.word.len() {
-            let player_letter = player_word.chars().nth(i).unwrap();
-            if self.word.contains(player_letter) {
-                let actual_letter = self.word.chars().nth(i).unwrap();
-                if player_letter == actual_letter {
-                    slots.push(SlotState::Match(player_letter));
-                } else {
-                    slots.push(SlotState::PartialMatch(player_letter));
-                }
-            } else {
-                slots.push(SlotState::NonMatch(player_letter));
+        let diff = score_guess(self.closest_answer(player_word), player_word);
+        self.board.feedback_mode.apply(diff)
+    }
+
+    fn near_misses(&self, diff: &Word, player_word: &str) -> Vec<char> {
+        let Word::Full(slots) = diff else {
+            return vec![];
+        };
+        slots
+            .iter()
+            .zip(self.closest_answer(player_word).chars())
+            .filter_map(|(ss, target)| match ss {
+                SlotState::NonMatch(c) if keyboard_adjacent(*c, target) => Some(*c),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn prepare_guess(&self, guess: &str) -> String {
+        match &self.guess_transform {
+            Some(transform) => transform(guess),
+            None => guess.to_owned(),
+        }
+        .to_lowercase()
+    }
+
+    fn check_guess(&self, guess: &str) -> Result<(), InputError> {
+        check_guess_shape(guess, self.board.width(), self.player.length_mode())?;
+        if let Some((letter, max)) = self.excess_repeat(guess) {
+            return Err(InputError::TooManyRepeats { letter, max });
+        }
+        if let Some(word_list) = &self.word_list {
+            if !word_list.contains(guess) {
+                return Err(InputError::NotInWordList(guess.to_owned()));
             }
         }
-        Word::Full(slots)
+        if self.hard_mode && !self.board.is_consistent(guess) {
+            return Err(InputError::ViolatesClues(guess.to_owned()));
+        }
+        Ok(())
+    }
+
+    pub fn is_legal_guess(&self, guess: &str) -> Result<(), InputError> {
+        self.check_guess(&self.prepare_guess(guess))
+    }
+
+    fn can_take_mulligan(&self) -> bool {
+        self.allow_mulligan && self.mulligans_used < MAX_MULLIGANS && self.current_word_index() > 0
+    }
+
+    fn take_mulligan(&mut self) {
+        let index = self.current_word_index() - 1;
+        if self.board.words[index].is_all_gray() {
+            self.score += self.all_gray_penalty as i32;
+        }
+        self.board.words[index] = Word::Empty(self.board.width);
+        self.attempts_left += 1;
+        self.mulligans_used += 1;
+    }
+
+    fn hearts(&self) -> String {
+        let used = self.board.rows().count();
+        format!(
+            "{}{}",
+            "❤".repeat(self.number_of_attempts as usize - used),
+            "🖤".repeat(used)
+        )
+    }
+
+    fn write_board<W: Write>(&self, out: &mut W) -> io::Result<()> {
+        if self.show_hearts {
+            writeln!(out, "{}", self.hearts())?;
+        }
+        self.board.write_to(&self.renderer, out)
     }
 
     fn current_word_index(&self) -> usize {
@@ -45,160 +515,2550 @@ impl<P: Player> Game<P> {
     }
 
     pub fn run(&mut self) -> GameSummary {
-        self.board.print();
+        self.run_with_output(&mut stdout())
+            .expect("failed to write the game to stdout")
+    }
+
+    pub fn run_with_output<W: Write>(&mut self, out: &mut W) -> io::Result<GameSummary> {
+        if self.show_legend {
+            print_legend(&self.renderer.theme, out)?;
+        }
+        self.write_board(out)?;
+        let mut solved_with = None;
+        let mut guess_times = vec![];
+        let mut candidate_counts = vec![];
         while self.attempts_left > 0 {
-            let player_word = self.player.get_play(&self.board);
+            if self.hard_mode {
+                print_template(&self.board, &self.renderer, out)?;
+            }
+            let started = Instant::now();
+            let player_word = loop {
+                if let Some(prompt) = self.player.prompt() {
+                    writeln!(out, "{}", prompt)?;
+                }
+                let Some(guess) = self.player.get_play(&self.board) else {
+                    break None;
+                };
+                if guess == MULLIGAN_COMMAND {
+                    if self.can_take_mulligan() {
+                        self.take_mulligan();
+                        guess_times.pop();
+                        candidate_counts.pop();
+                        self.write_board(out)?;
+                    } else {
+                        writeln!(out, "{}", "No mulligan available, try again".yellow())?;
+                    }
+                    continue;
+                }
+                let guess = self.prepare_guess(&guess);
+                if let Err(e) = self.check_guess(&guess) {
+                    writeln!(out, "{}", format!("{}, try again", e).yellow())?;
+                    continue;
+                }
+                if self.warn_impossible && !self.board.is_consistent(&guess) {
+                    writeln!(
+                        out,
+                        "{}",
+                        "This word can't be the answer based on your clues".yellow()
+                    )?;
+                }
+                self.player.guess_accepted();
+                break Some(guess);
+            };
+            let Some(player_word) = player_word else {
+                break;
+            };
+            guess_times.push(started.elapsed());
             let diff = self.get_diff(&player_word);
+            if self.verbose {
+                writeln!(out, "{}", diff.describe())?;
+            }
+            if let (true, Some(word_list)) = (self.coach, &self.word_list) {
+                let grade = grade_guess(&self.board, &player_word, word_list);
+                writeln!(out, "Guess grade: {}", grade)?;
+            }
+            let near_misses = if self.near_miss_hints {
+                self.near_misses(&diff, &player_word)
+            } else {
+                vec![]
+            };
+            if diff.is_all_gray() {
+                self.score -= self.all_gray_penalty as i32;
+            }
+            let candidates_before = self
+                .word_list
+                .as_ref()
+                .map(|word_list| word_list.candidates(&self.board).count());
             self.board.add_word(diff, self.current_word_index());
-            self.board.print();
-            if player_word == self.word {
+            if let (Some(before), Some(word_list)) = (candidates_before, &self.word_list) {
+                let remaining: Vec<&str> = word_list.candidates(&self.board).collect();
+                let after = remaining.len();
+                candidate_counts.push((before, after));
+                if self.show_eliminations {
+                    writeln!(
+                        out,
+                        "Eliminated {} candidates",
+                        before.saturating_sub(after)
+                    )?;
+                }
+                if let Some(callback) = &mut self.progress_callback {
+                    callback(after);
+                }
+                if let Some(max_rank) = self.common_word_rank {
+                    let common = remaining
+                        .iter()
+                        .filter(|w| word_list.rank(w).is_some_and(|rank| rank <= max_rank))
+                        .count();
+                    writeln!(out, "~{} common words remaining", common)?;
+                }
+            }
+            if let (true, Some(word_list)) = (self.show_attempt_estimate, &self.word_list) {
+                if !self.is_answer(&player_word) {
+                    let estimate = expected_attempts(&self.board, word_list);
+                    writeln!(out, "est. {:.1} more guesses", estimate)?;
+                }
+            }
+            if let Some(spectator) = &self.spectator {
+                if spectator.send(self.board.snapshot()).is_err() {
+                    self.spectator = None;
+                }
+            }
+            let solved = self.is_answer(&player_word);
+            if !solved {
+                self.attempts_left -= 1;
+            }
+            self.write_board(out)?;
+            if !near_misses.is_empty() {
+                let letters: Vec<String> = near_misses.iter().map(char::to_string).collect();
+                writeln!(
+                    out,
+                    "{}",
+                    format!("Typo-likely: {}", letters.join(", ")).italic()
+                )?;
+            }
+            if solved {
+                solved_with = Some(player_word);
                 break;
             }
-            self.attempts_left -= 1;
+            if self.show_attempts_remaining {
+                writeln!(out, "Attempts left: {}", self.attempts_left)?;
+            }
         }
-        GameSummary {
-            won: self.attempts_left > 0,
+        let won = solved_with.is_some();
+        let target = solved_with.as_deref().unwrap_or(&self.word);
+        let mut summary = GameSummary::from_board(won, guess_times, &self.board, target);
+        summary.score = self.score;
+        summary.candidate_counts = candidate_counts;
+        summary.metadata = self.metadata.clone();
+        if let Some(word_list) = &self.word_list {
+            summary.anagrams = word_list
+                .anagrams_of(&summary.target)
+                .into_iter()
+                .map(str::to_owned)
+                .collect();
         }
+        Ok(summary)
     }
 }
 
-impl Default for Game<HumanPlayer> {
-    fn default() -> Self {
-        Self::new("test".to_owned(), 5, HumanPlayer { word_length: 4 })
+impl Game<HumanPlayer> {
+    pub fn with_word(word: String) -> Self {
+        let player = HumanPlayer::new(word.chars().count() as u8);
+        Self::new(word, DEFAULT_ATTEMPTS, player)
     }
 }
 
-pub struct Board {
-    words: Vec<Word>,
-}
-
-impl Board {
-    fn new(width: u8, length: u8) -> Self {
-        Self {
-            words: (0..length).map(|_| Word::Empty(width)).collect(),
+impl Game<HumanPlayer> {
+    pub fn from_args(args: &[String]) -> Result<Self, GameError> {
+        let mut word = None;
+        let mut number_of_attempts = DEFAULT_ATTEMPTS;
+        let mut hard_mode = false;
+        let mut word_list = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| GameError::InvalidArgument(format!("{} needs a value", arg)))
+            };
+            match arg.as_str() {
+                "--word" => word = Some(value()?.to_lowercase()),
+                "--attempts" => {
+                    let attempts = value()?;
+                    number_of_attempts =
+                        attempts.parse().ok().filter(|&n| n > 0).ok_or_else(|| {
+                            GameError::InvalidArgument(format!("invalid attempts '{}'", attempts))
+                        })?;
+                }
+                "--hard" => hard_mode = true,
+                "--list" => {
+                    let path = value()?;
+                    word_list = Some(
+                        WordList::from_file(path)
+                            .map_err(|e| GameError::WordListFile(e.to_string()))?,
+                    );
+                }
+                _ => return Err(GameError::InvalidArgument(arg.clone())),
+            }
         }
+        let word = match (word, &word_list) {
+            (Some(word), _) => word,
+            (None, Some(word_list)) => word_list
+                .random_word(&mut thread_rng())
+                .ok_or(GameError::EmptyWord)?
+                .to_owned(),
+            (None, None) => random_word(&mut thread_rng()).to_owned(),
+        };
+        let player = HumanPlayer::new(word.chars().count() as u8);
+        Ok(Self::try_new(word, number_of_attempts, player, word_list)?.with_hard_mode(hard_mode))
     }
-    fn add_word(&mut self, word: Word, index: usize) {
-        self.words[index] = word;
+}
+
+impl Default for Game<HumanPlayer> {
+    fn default() -> Self {
+        Self::with_word(random_word(&mut thread_rng()).to_owned())
     }
 }
 
-impl Board {
-    fn print(&self) {
-        for w in &self.words {
-            print!("{}", "|".blue());
-            w.print();
-            print!("{}", "|\n".blue());
+pub fn score_guess(target: &str, guess: &str) -> Word {
+    let pairs: Vec<(char, char)> = guess.chars().zip(target.chars()).collect();
+    let mut unmatched: HashMap<char, usize> = HashMap::new();
+    for (i, target_letter) in target.chars().enumerate() {
+        if pairs
+            .get(i)
+            .is_none_or(|(guess_letter, _)| *guess_letter != target_letter)
+        {
+            *unmatched.entry(target_letter).or_insert(0) += 1;
         }
     }
+    let mut slots: Vec<SlotState> = pairs
+        .iter()
+        .map(|&(guess_letter, target_letter)| {
+            if guess_letter == target_letter {
+                return SlotState::Match(guess_letter);
+            }
+            match unmatched.get_mut(&guess_letter) {
+                Some(left) if *left > 0 => {
+                    *left -= 1;
+                    SlotState::PartialMatch(guess_letter)
+                }
+                _ => SlotState::NonMatch(guess_letter),
+            }
+        })
+        .collect();
+    let target_length = target.chars().count();
+    slots.extend(guess.chars().skip(target_length).map(SlotState::Extra));
+    Word::Full(slots)
 }
 
-enum Word {
-    Full(Vec<SlotState>),
-    Empty(u8),
+pub fn simulate(guess: &str, hypothetical_target: &str) -> Word {
+    score_guess(hypothetical_target, guess)
 }
 
-impl Default for Word {
-    fn default() -> Self {
-        Self::Empty(0)
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HardModeViolation {
+    MissingGreen { position: usize, letter: char },
+    UnusedYellow(char),
 }
 
-impl Word {
-    fn print(&self) {
-        match self {
-            Self::Empty(n) => print!("{}", "#".repeat(*n as usize).to_owned().blue()),
-            Self::Full(v) => {
-                for ss in v {
-                    print!("{}", ss.to_colored_string());
-                }
+pub fn hard_mode_violation(board: &Board, guess: &str) -> Option<HardModeViolation> {
+    let letters: Vec<char> = guess.chars().collect();
+    if let Some((position, letter)) = board
+        .correct_positions()
+        .into_iter()
+        .find(|&(i, c)| letters.get(i) != Some(&c))
+    {
+        return Some(HardModeViolation::MissingGreen { position, letter });
+    }
+    board
+        .rows()
+        .flat_map(|w| match w {
+            Word::Full(slots) => slots.as_slice(),
+            Word::Empty(_) => &[],
+        })
+        .find_map(|ss| match ss {
+            SlotState::PartialMatch(c) if !letters.contains(c) => {
+                Some(HardModeViolation::UnusedYellow(*c))
             }
+            _ => None,
+        })
+}
+
+pub(crate) fn check_guess_shape(
+    guess: &str,
+    width: usize,
+    length_mode: LengthMode,
+) -> Result<(), InputError> {
+    let found = guess.chars().count();
+    let length_ok = match length_mode {
+        LengthMode::Exact => found == width,
+        LengthMode::FreeLength => found > 0,
+    };
+    if !length_ok {
+        return Err(InputError::WrongLength {
+            expected: width,
+            found,
+        });
+    }
+    match guess.chars().find(|c| !c.is_alphabetic()) {
+        Some(c) => Err(InputError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
+}
+
+pub(crate) fn check_player_length<P: Player>(word: &str, player: &P) -> Result<(), GameError> {
+    if let Some(word_length) = player.word_length() {
+        let found = word.chars().count();
+        if word_length as usize != found {
+            return Err(GameError::LengthMismatch {
+                expected: word_length as usize,
+                found,
+            });
         }
     }
+    Ok(())
 }
 
-enum SlotState {
-    NonMatch(char),
-    PartialMatch(char),
-    Match(char),
+pub(crate) fn check_target_chars(word: &str) -> Result<(), GameError> {
+    if word.is_empty() {
+        return Err(GameError::EmptyWord);
+    }
+    match word.to_lowercase().chars().find(|c| !ALPHABET.contains(*c)) {
+        Some(c) => Err(GameError::InvalidCharacter(c)),
+        None => Ok(()),
+    }
 }
 
-impl SlotState {
-    fn to_colored_string(&self) -> ColoredString {
-        match self {
-            Self::Match(n) => n.to_string().green(),
-            Self::NonMatch(n) => n.to_string().white(),
-            Self::PartialMatch(n) => n.to_string().yellow(),
-        }
+pub fn is_valid_target(word: &str, word_list: &WordList) -> Result<(), GameError> {
+    check_target_chars(word)?;
+    if !word_list.contains(word) {
+        return Err(GameError::NotInWordList(word.to_lowercase()));
     }
+    Ok(())
 }
 
-#[derive(Debug)]
-pub struct GameSummary {
-    won: bool,
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ u64::from(*b)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
 }
 
-pub trait Player {
-    fn get_play(&self, board: &Board) -> String;
+fn random_word(rng: &mut dyn RngCore) -> &'static str {
+    WORDS
+        .choose(rng)
+        .expect("the builtin word list is not empty")
 }
 
-pub struct HumanPlayer {
-    word_length: u8,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GrayMeaning {
+    /// A candidate must reproduce every row's feedback exactly, so a gray letter
+    /// has no copies in the answer beyond those already colored in its row.
+    #[default]
+    AbsentFromWord,
+    /// A gray letter only rules out the positions nobody has solved yet; the
+    /// letter may still sit under a green tile elsewhere on the board.
+    AbsentFromUnsolved,
 }
 
-impl HumanPlayer {
-    fn validate_input(&self, input: &str) -> bool {
-        input.len() == self.word_length as usize
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoardSnapshot {
+    pub words: Vec<Word>,
+}
+
+pub struct Board {
+    width: u8,
+    words: Vec<Word>,
+    hints: Vec<(usize, char)>,
+    fixed_clues: Vec<(usize, SlotState)>,
+    gray_meaning: GrayMeaning,
+    feedback_mode: FeedbackMode,
+}
+
+impl Board {
+    pub fn new(width: u8, length: u8) -> Self {
+        Self {
+            width,
+            hints: vec![],
+            fixed_clues: vec![],
+            gray_meaning: GrayMeaning::AbsentFromWord,
+            feedback_mode: FeedbackMode::Full,
+            words: (0..length).map(|_| Word::Empty(width)).collect(),
+        }
+    }
+    pub fn add_word(&mut self, word: Word, index: usize) {
+        self.words[index] = word;
     }
 
-    fn get_player_word(&self) -> String {
-        loop {
-            println!("Insert your guess: ");
-            let mut buffer = String::new();
-            match stdin().read_line(&mut buffer) {
-                Ok(_) => {
-                    if !self.validate_input(buffer.trim()) {
-                        println!("{}", "Invalid input, try again".yellow());
-                        continue;
-                    }
-                    break buffer.trim().to_owned();
-                }
-                Err(_) => {
-                    println!(
-                        "{}",
-                        "There was an error while reading the input, try again".red()
-                    );
-                    continue;
-                }
-            }
+    fn clear(&mut self) {
+        for w in self.words.iter_mut() {
+            *w = Word::Empty(self.width);
         }
+        self.hints.clear();
     }
-}
 
-impl Player for HumanPlayer {
-    fn get_play(&self, _: &Board) -> String {
-        self.get_player_word()
+    pub fn set_fixed_clue(&mut self, position: usize, clue: SlotState) {
+        assert!(
+            position < self.width as usize,
+            "fixed clue position is outside the board"
+        );
+        self.fixed_clues.retain(|(pos, _)| *pos != position);
+        self.fixed_clues.push((position, clue));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    #[test]
-    fn test_should_validate_length_ok() {
-        let game: Game<HumanPlayer> = Game::default();
-        assert!(game.player.validate_input("Test"))
+    pub fn snapshot(&self) -> BoardSnapshot {
+        BoardSnapshot {
+            words: self.words.clone(),
+        }
     }
 
-    #[test]
-    fn should_not_validate_greater_length() {
-        let game: Game<HumanPlayer> = Game::default();
-        assert!(!game.player.validate_input("Hello World"))
+    pub fn newly_revealed(&self, before: &BoardSnapshot) -> Vec<(char, SlotState)> {
+        let known = best_letter_states(&before.words);
+        best_letter_states(&self.words)
+            .into_iter()
+            .filter(|(c, ss)| known.get(c).is_none_or(|old| ss.rank() > old.rank()))
+            .collect()
     }
 
-    #[test]
+    pub fn width(&self) -> usize {
+        self.width as usize
+    }
+
+    pub fn correct_positions(&self) -> Vec<(usize, char)> {
+        let mut positions: Vec<(usize, char)> = self
+            .words
+            .iter()
+            .filter_map(|w| match w {
+                Word::Full(slots) => Some(slots),
+                Word::Empty(_) => None,
+            })
+            .flat_map(|slots| slots.iter().enumerate())
+            .filter_map(|(i, ss)| match ss {
+                SlotState::Match(c) => Some((i, *c)),
+                _ => None,
+            })
+            .collect();
+        positions.sort_unstable();
+        positions.dedup();
+        positions
+    }
+
+    pub fn is_consistent(&self, candidate: &str) -> bool {
+        if candidate.chars().count() != self.width as usize {
+            return false;
+        }
+        let hints_hold = self
+            .hints
+            .iter()
+            .all(|(i, c)| candidate.chars().nth(*i) == Some(*c));
+        if !hints_hold {
+            return false;
+        }
+        match self.gray_meaning {
+            GrayMeaning::AbsentFromWord => self.words.iter().all(|w| match w.guess() {
+                Some(guess) => self.feedback_mode.apply(score_guess(candidate, &guess)) == *w,
+                None => true,
+            }),
+            GrayMeaning::AbsentFromUnsolved => self.fits_unsolved_grays(candidate),
+        }
+    }
+
+    fn fits_unsolved_grays(&self, candidate: &str) -> bool {
+        let letters: Vec<char> = candidate.chars().collect();
+        let solved: Vec<usize> = self.correct_positions().iter().map(|(i, _)| *i).collect();
+        let unsolved_copies = |c: char| {
+            letters
+                .iter()
+                .enumerate()
+                .filter(|(j, l)| **l == c && !solved.contains(j))
+                .count()
+        };
+        self.rows().all(|w| {
+            let Word::Full(slots) = w else {
+                return true;
+            };
+            // Greens sit on solved positions, so only this row's yellow copies
+            // of a gray letter may still be hiding among the unsolved ones.
+            slots.iter().enumerate().all(|(i, ss)| match ss {
+                SlotState::Match(c) => letters[i] == *c,
+                SlotState::PartialMatch(c) => letters[i] != *c && letters.contains(c),
+                SlotState::NonMatch(c) if self.feedback_mode == FeedbackMode::GreensOnly => {
+                    letters[i] != *c
+                }
+                SlotState::NonMatch(c) => {
+                    let yellows = slots
+                        .iter()
+                        .filter(|other| **other == SlotState::PartialMatch(*c))
+                        .count();
+                    letters[i] != *c && unsolved_copies(*c) <= yellows
+                }
+                SlotState::Extra(_) => true,
+            })
+        })
+    }
+
+    pub fn set_gray_meaning(&mut self, gray_meaning: GrayMeaning) {
+        self.gray_meaning = gray_meaning;
+    }
+
+    pub fn set_feedback_mode(&mut self, feedback_mode: FeedbackMode) {
+        self.feedback_mode = feedback_mode;
+    }
+
+    fn slots(&self) -> impl Iterator<Item = &SlotState> {
+        self.words.iter().flat_map(|w| match w {
+            Word::Full(slots) => slots.as_slice(),
+            Word::Empty(_) => &[],
+        })
+    }
+
+    pub fn total_greens(&self) -> usize {
+        self.slots()
+            .filter(|ss| matches!(ss, SlotState::Match(_)))
+            .count()
+    }
+
+    pub fn total_yellows(&self) -> usize {
+        self.slots()
+            .filter(|ss| matches!(ss, SlotState::PartialMatch(_)))
+            .count()
+    }
+
+    pub fn unique_greens(&self) -> usize {
+        self.correct_positions().len()
+    }
+
+    pub fn forced_guess(&self, word_list: &WordList) -> Option<String> {
+        let mut candidates = word_list.candidates(self);
+        match (candidates.next(), candidates.next()) {
+            (Some(only), None) => Some(only.to_owned()),
+            _ => None,
+        }
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &Word> {
+        self.words.iter().filter(|w| matches!(w, Word::Full(_)))
+    }
+
+    pub fn patterns(&self) -> Vec<u32> {
+        self.words
+            .iter()
+            .filter(|w| matches!(w, Word::Full(_)))
+            .map(Word::pattern_code)
+            .collect()
+    }
+
+    pub fn to_compact_string(&self) -> String {
+        self.words
+            .iter()
+            .filter_map(|w| w.guess().map(|guess| format!("{}:{}", guess, w.pattern())))
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+
+    pub fn unknown_positions(&self) -> Vec<usize> {
+        let known: Vec<usize> = self.correct_positions().iter().map(|(i, _)| *i).collect();
+        (0..self.width as usize)
+            .filter(|i| !known.contains(i))
+            .collect()
+    }
+}
+
+impl Board {
+    fn hint_row(&self, theme: &Theme) -> Option<String> {
+        if self.hints.is_empty() {
+            return None;
+        }
+        let slots: String = (0..self.width as usize)
+            .map(|i| match self.hints.iter().find(|(pos, _)| *pos == i) {
+                Some((_, c)) => c.to_string().color(theme.matched).to_string(),
+                None => "#".color(theme.empty).to_string(),
+            })
+            .collect();
+        Some(format!(
+            "{}{}{}",
+            "|".color(theme.border),
+            slots,
+            "|".color(theme.border)
+        ))
+    }
+
+    fn fixed_clue_row(&self, theme: &Theme) -> Option<String> {
+        if self.fixed_clues.is_empty() {
+            return None;
+        }
+        let slots: String = (0..self.width as usize)
+            .map(
+                |i| match self.fixed_clues.iter().find(|(pos, _)| *pos == i) {
+                    Some((_, clue)) => clue.to_colored_string(theme).underline().to_string(),
+                    None => "#".color(theme.empty).to_string(),
+                },
+            )
+            .collect();
+        Some(format!(
+            "{}{}{}",
+            "|".color(theme.border),
+            slots,
+            "|".color(theme.border)
+        ))
+    }
+
+    fn rendered_rows(&self, theme: &Theme) -> Vec<Vec<String>> {
+        let border = "|".color(theme.border).to_string();
+        self.hint_row(theme)
+            .into_iter()
+            .chain(self.fixed_clue_row(theme))
+            .map(|row| vec![row])
+            .chain(self.words.iter().map(|w| {
+                let tiles = match w {
+                    Word::Full(slots) => slots
+                        .iter()
+                        .map(|ss| ss.to_colored_string(theme).to_string())
+                        .collect(),
+                    Word::Empty(_) => vec![w.render(theme)],
+                };
+                std::iter::once(border.clone())
+                    .chain(tiles)
+                    .chain(std::iter::once(border.clone()))
+                    .collect()
+            }))
+            .collect()
+    }
+
+    pub fn render(&self, renderer: &Renderer) -> String {
+        join_rows(self.rendered_rows(&renderer.theme), renderer.orientation)
+    }
+
+    pub fn to_ansi_string(&self, theme: &Theme) -> String {
+        // Built by hand: `colored` drops its escapes whenever stdout isn't a
+        // terminal, which is exactly where bots call this from.
+        let paint = |text: &str, color: Color, style: &str| {
+            format!("\x1b[{}{}m{}\x1b[0m", style, color.to_fg_str(), text)
+        };
+        let tile = |ss: &SlotState, style: &str| {
+            let (color, style) = match ss {
+                SlotState::Match(_) => (theme.matched, style.to_owned()),
+                SlotState::PartialMatch(_) => (theme.partial_match, style.to_owned()),
+                SlotState::NonMatch(_) => (theme.non_match, style.to_owned()),
+                SlotState::Extra(_) => (theme.non_match, format!("{}9;", style)),
+            };
+            paint(&ss.letter().to_string(), color, &style)
+        };
+        let border = paint("|", theme.border, "");
+        let header = |known: &dyn Fn(usize) -> Option<String>| {
+            (0..self.width as usize)
+                .map(|i| known(i).unwrap_or_else(|| paint("#", theme.empty, "")))
+                .collect::<String>()
+        };
+        let mut rows = vec![];
+        if !self.hints.is_empty() {
+            rows.push(header(&|i| {
+                self.hints
+                    .iter()
+                    .find(|(pos, _)| *pos == i)
+                    .map(|(_, c)| paint(&c.to_string(), theme.matched, ""))
+            }));
+        }
+        if !self.fixed_clues.is_empty() {
+            rows.push(header(&|i| {
+                self.fixed_clues
+                    .iter()
+                    .find(|(pos, _)| *pos == i)
+                    .map(|(_, clue)| tile(clue, "4;"))
+            }));
+        }
+        rows.extend(self.words.iter().map(|w| match w {
+            Word::Full(slots) => slots.iter().map(|ss| tile(ss, "")).collect(),
+            Word::Empty(n) => paint(&"#".repeat(*n as usize), theme.empty, ""),
+        }));
+        rows.iter()
+            .map(|row| format!("{}{}{}\n", border, row, border))
+            .collect()
+    }
+
+    pub fn render_preview(&self, preview: &Word, renderer: &Renderer) -> String {
+        let theme = &renderer.theme;
+        let mut rows = self.rendered_rows(theme);
+        let headers = rows.len() - self.words.len();
+        if let (Some(i), Word::Full(slots)) = (
+            self.words.iter().position(|w| matches!(w, Word::Empty(_))),
+            preview,
+        ) {
+            let border = "|".color(theme.border).to_string();
+            rows[headers + i] = std::iter::once(border.clone())
+                .chain(
+                    slots
+                        .iter()
+                        .map(|ss| ss.to_colored_string(theme).dimmed().to_string()),
+                )
+                .chain(std::iter::once(border))
+                .collect();
+        }
+        join_rows(rows, renderer.orientation)
+    }
+
+    pub fn write_to<W: Write>(&self, renderer: &Renderer, out: &mut W) -> io::Result<()> {
+        if renderer.reveal_order == RevealOrder::Simultaneous {
+            return write!(out, "{}", self.render(renderer));
+        }
+        let rows = self.rendered_rows(&renderer.theme);
+        let headers = rows.len() - self.words.len();
+        let revealing = self
+            .words
+            .iter()
+            .rposition(|w| matches!(w, Word::Full(_)))
+            .map(|i| i + headers);
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 && renderer.orientation == Orientation::Horizontal {
+                write!(out, " ")?;
+            }
+            for (j, piece) in row.iter().enumerate() {
+                write!(out, "{}", piece)?;
+                if Some(i) == revealing && j > 0 && j < row.len() - 1 {
+                    out.flush()?;
+                    thread::sleep(renderer.reveal_delay);
+                }
+            }
+            if renderer.orientation == Orientation::Vertical {
+                writeln!(out)?;
+            }
+        }
+        if renderer.orientation == Orientation::Horizontal {
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    pub fn to_rgba(&self, theme: &Theme, tile_px: u32) -> (Vec<u8>, u32, u32) {
+        let width = self.width as u32 * tile_px;
+        let height = self.words.len() as u32 * tile_px;
+        let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+        for w in &self.words {
+            let tiles: Vec<[u8; 3]> = (0..self.width as usize)
+                .map(|i| match w {
+                    Word::Full(slots) => match slots.get(i) {
+                        Some(SlotState::Match(_)) => to_rgb(theme.matched),
+                        Some(SlotState::PartialMatch(_)) => to_rgb(theme.partial_match),
+                        Some(SlotState::NonMatch(_) | SlotState::Extra(_)) => {
+                            to_rgb(theme.non_match)
+                        }
+                        None => to_rgb(theme.empty),
+                    },
+                    Word::Empty(_) => to_rgb(theme.empty),
+                })
+                .collect();
+            for _ in 0..tile_px {
+                for [r, g, b] in &tiles {
+                    for _ in 0..tile_px {
+                        pixels.extend_from_slice(&[*r, *g, *b, 255]);
+                    }
+                }
+            }
+        }
+        (pixels, width, height)
+    }
+
+    pub fn print(&self, renderer: &Renderer) {
+        print!("{}", self.render(renderer));
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Word {
+    Full(Vec<SlotState>),
+    Empty(u8),
+}
+
+impl Default for Word {
+    fn default() -> Self {
+        Self::Empty(0)
+    }
+}
+
+impl Word {
+    pub fn pattern(&self) -> String {
+        match self {
+            Self::Empty(n) => " ".repeat(*n as usize),
+            Self::Full(v) => v.iter().map(SlotState::code).collect(),
+        }
+    }
+
+    pub fn pattern_code(&self) -> u32 {
+        match self {
+            Self::Empty(_) => 0,
+            Self::Full(v) => v.iter().fold(0, |code, ss| {
+                code * 3
+                    + match ss {
+                        SlotState::Match(_) => 2,
+                        SlotState::PartialMatch(_) => 1,
+                        SlotState::NonMatch(_) | SlotState::Extra(_) => 0,
+                    }
+            }),
+        }
+    }
+
+    pub fn is_all_gray(&self) -> bool {
+        match self {
+            Self::Empty(_) => false,
+            Self::Full(v) => v
+                .iter()
+                .all(|ss| matches!(ss, SlotState::NonMatch(_) | SlotState::Extra(_))),
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        match self {
+            Self::Empty(_) => false,
+            Self::Full(v) => v.iter().all(|ss| matches!(ss, SlotState::Match(_))),
+        }
+    }
+
+    pub fn guess(&self) -> Option<String> {
+        match self {
+            Self::Empty(_) => None,
+            Self::Full(v) => Some(v.iter().map(SlotState::letter).collect()),
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Empty(n) => format!("{} empty slots", n),
+            Self::Full(v) => v
+                .iter()
+                .map(|ss| {
+                    let (c, state) = match ss {
+                        SlotState::Match(c) => (c, "correct"),
+                        SlotState::PartialMatch(c) => (c, "wrong spot"),
+                        SlotState::NonMatch(c) => (c, "absent"),
+                        SlotState::Extra(c) => (c, "extra"),
+                    };
+                    format!("{}: {}", c.to_ascii_uppercase(), state)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    fn render(&self, theme: &Theme) -> String {
+        match self {
+            Self::Empty(n) => "#".repeat(*n as usize).color(theme.empty).to_string(),
+            Self::Full(v) => v
+                .iter()
+                .map(|ss| ss.to_colored_string(theme).to_string())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SlotState {
+    NonMatch(char),
+    PartialMatch(char),
+    Match(char),
+    Extra(char),
+}
+
+fn join_rows(rows: Vec<Vec<String>>, orientation: Orientation) -> String {
+    let rows: Vec<String> = rows.into_iter().map(|row| row.concat()).collect();
+    match orientation {
+        Orientation::Vertical => rows.iter().map(|row| format!("{}\n", row)).collect(),
+        Orientation::Horizontal => format!("{}\n", rows.join(" ")),
+    }
+}
+
+fn best_letter_states(words: &[Word]) -> BTreeMap<char, SlotState> {
+    let mut best: BTreeMap<char, SlotState> = BTreeMap::new();
+    for ss in words.iter().flat_map(|w| match w {
+        Word::Full(slots) => slots.as_slice(),
+        Word::Empty(_) => &[],
+    }) {
+        if matches!(ss, SlotState::Extra(_)) {
+            continue;
+        }
+        let entry = best.entry(ss.letter()).or_insert_with(|| ss.clone());
+        if ss.rank() > entry.rank() {
+            *entry = ss.clone();
+        }
+    }
+    best
+}
+
+impl SlotState {
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Match(_) => 2,
+            Self::PartialMatch(_) => 1,
+            Self::NonMatch(_) | Self::Extra(_) => 0,
+        }
+    }
+
+    pub fn letter(&self) -> char {
+        match self {
+            Self::Match(c) | Self::PartialMatch(c) | Self::NonMatch(c) | Self::Extra(c) => *c,
+        }
+    }
+
+    pub fn code(&self) -> char {
+        match self {
+            Self::Match(_) => 'G',
+            Self::PartialMatch(_) => 'Y',
+            Self::NonMatch(_) => 'X',
+            Self::Extra(_) => '-',
+        }
+    }
+
+    fn to_colored_string(&self, theme: &Theme) -> ColoredString {
+        match self {
+            Self::Match(n) => n.to_string().color(theme.matched),
+            Self::NonMatch(n) => n.to_string().color(theme.non_match),
+            Self::Extra(n) => n.to_string().color(theme.non_match).strikethrough(),
+            Self::PartialMatch(n) => n.to_string().color(theme.partial_match),
+        }
+    }
+}
+
+impl fmt::Display for SlotState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.code().to_ascii_lowercase(), self.letter())
+    }
+}
+
+impl Serialize for SlotState {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SlotState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+impl FromStr for SlotState {
+    type Err = ParseSlotStateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        let (Some(code), Some(':'), Some(c), None) =
+            (chars.next(), chars.next(), chars.next(), chars.next())
+        else {
+            return Err(ParseSlotStateError(s.to_owned()));
+        };
+        match code {
+            'g' => Ok(Self::Match(c)),
+            'y' => Ok(Self::PartialMatch(c)),
+            'x' => Ok(Self::NonMatch(c)),
+            '-' => Ok(Self::Extra(c)),
+            _ => Err(ParseSlotStateError(s.to_owned())),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameSummary {
+    pub won: bool,
+    pub target: String,
+    pub attempts_used: usize,
+    pub guesses: Vec<String>,
+    pub grid: Vec<Vec<SlotState>>,
+    pub guess_times: Vec<Duration>,
+    pub distinct_letters_tried: usize,
+    pub wasted_guesses: usize,
+    pub solved_by: Option<String>,
+    pub anagrams: Vec<String>,
+    pub candidate_counts: Vec<(usize, usize)>,
+    pub metadata: HashMap<String, String>,
+    pub score: i32,
+}
+
+impl GameSummary {
+    pub(crate) fn from_board(
+        won: bool,
+        guess_times: Vec<Duration>,
+        board: &Board,
+        target: &str,
+    ) -> Self {
+        let guesses: Vec<String> = board.words.iter().filter_map(Word::guess).collect();
+        let grid: Vec<Vec<SlotState>> = board
+            .words
+            .iter()
+            .filter_map(|w| match w {
+                Word::Full(slots) => Some(slots.clone()),
+                Word::Empty(_) => None,
+            })
+            .collect();
+        let wasted_guesses = board.words.iter().filter(|w| w.is_all_gray()).count();
+        let mut letters: Vec<char> = guesses.iter().flat_map(|g| g.chars()).collect();
+        letters.sort_unstable();
+        letters.dedup();
+        Self {
+            won,
+            target: target.to_owned(),
+            attempts_used: guesses.len(),
+            guesses,
+            grid,
+            guess_times,
+            distinct_letters_tried: letters.len(),
+            wasted_guesses,
+            solved_by: None,
+            anagrams: vec![],
+            candidate_counts: vec![],
+            metadata: HashMap::new(),
+            score: 0,
+        }
+    }
+
+    pub fn best_guess_distance(&self) -> Option<usize> {
+        self.guesses
+            .iter()
+            .map(|g| levenshtein(g, &self.target))
+            .min()
+    }
+
+    pub fn alphabet_coverage(&self) -> f64 {
+        let tried = ALPHABET
+            .chars()
+            .filter(|c| self.guesses.iter().any(|g| g.contains(*c)))
+            .count();
+        tried as f64 / ALPHABET.len() as f64
+    }
+
+    pub fn best_similarity(&self) -> Option<f64> {
+        self.guesses
+            .iter()
+            .map(|g| word_similarity(g, &self.target))
+            .reduce(f64::max)
+    }
+
+    pub fn solve_time(&self) -> Duration {
+        self.guess_times.iter().sum()
+    }
+
+    pub fn eliminations(&self) -> Vec<usize> {
+        self.candidate_counts
+            .iter()
+            .map(|&(before, after)| before.saturating_sub(after))
+            .collect()
+    }
+
+    pub fn information_per_guess(&self) -> Vec<f64> {
+        self.candidate_counts
+            .iter()
+            .map(|&(before, after)| (before.max(1) as f64 / after.max(1) as f64).log2().max(0.0))
+            .collect()
+    }
+
+    /// The surprisal `-log2 p` of each guess's feedback. With every remaining
+    /// candidate equally likely, `p` is `after / before`, so this is the same
+    /// number as the information the guess gained.
+    pub fn guess_surprise(&self) -> Vec<f64> {
+        self.information_per_guess()
+    }
+
+    pub fn pattern_grid(&self) -> Vec<Vec<SlotState>> {
+        self.grid
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .filter_map(|ss| match ss {
+                        SlotState::Match(_) => Some(SlotState::Match(PLACEHOLDER)),
+                        SlotState::PartialMatch(_) => Some(SlotState::PartialMatch(PLACEHOLDER)),
+                        SlotState::NonMatch(_) => Some(SlotState::NonMatch(PLACEHOLDER)),
+                        SlotState::Extra(_) => None,
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn pattern_grid_string(&self) -> String {
+        self.pattern_grid()
+            .iter()
+            .map(|row| row.iter().map(SlotState::code).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+pub trait Player {
+    /// Returns `None` once the player has no guesses left to give, e.g. when
+    /// their input has been closed.
+    fn get_play(&mut self, board: &Board) -> Option<String>;
+
+    fn word_length(&self) -> Option<u8> {
+        None
+    }
+
+    fn length_mode(&self) -> LengthMode {
+        LengthMode::Exact
+    }
+
+    /// A line the game writes to its output before asking for each guess.
+    fn prompt(&self) -> Option<String> {
+        None
+    }
+
+    /// Called once the game has accepted the last guess from `get_play`.
+    fn guess_accepted(&mut self) {}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LengthMode {
+    #[default]
+    Exact,
+    FreeLength,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WhitespacePolicy {
+    #[default]
+    Reject,
+    Strip,
+}
+
+pub struct HumanPlayer {
+    word_length: u8,
+    length_mode: LengthMode,
+    whitespace_policy: WhitespacePolicy,
+    confirm_guesses: bool,
+    auto_fill_greens: bool,
+    // `None` reads through stdin's shared buffer one line at a time, so other
+    // readers (and other players) still see the lines meant for them.
+    input: Option<Box<dyn BufRead>>,
+}
+
+impl HumanPlayer {
+    pub fn new(word_length: u8) -> Self {
+        Self {
+            word_length,
+            length_mode: LengthMode::Exact,
+            whitespace_policy: WhitespacePolicy::Reject,
+            confirm_guesses: false,
+            auto_fill_greens: false,
+            input: None,
+        }
+    }
+
+    pub fn with_length_mode(mut self, length_mode: LengthMode) -> Self {
+        self.length_mode = length_mode;
+        self
+    }
+
+    pub fn with_whitespace_policy(mut self, whitespace_policy: WhitespacePolicy) -> Self {
+        self.whitespace_policy = whitespace_policy;
+        self
+    }
+
+    pub fn with_confirmation(mut self, confirm_guesses: bool) -> Self {
+        self.confirm_guesses = confirm_guesses;
+        self
+    }
+
+    pub fn with_auto_fill_greens(mut self, auto_fill_greens: bool) -> Self {
+        self.auto_fill_greens = auto_fill_greens;
+        self
+    }
+
+    pub fn with_input<R: BufRead + 'static>(mut self, input: R) -> Self {
+        self.input = Some(Box::new(input));
+        self
+    }
+
+    fn apply_whitespace_policy(&self, input: &str) -> Result<String, &'static str> {
+        if !input.chars().any(char::is_whitespace) {
+            return Ok(input.to_owned());
+        }
+        match self.whitespace_policy {
+            WhitespacePolicy::Reject => Err("Guesses can't contain spaces, try again"),
+            WhitespacePolicy::Strip => Ok(input.chars().filter(|c| !c.is_whitespace()).collect()),
+        }
+    }
+
+    fn validate_input(&self, input: &str) -> bool {
+        if input.chars().any(char::is_whitespace) {
+            return false;
+        }
+        match self.length_mode {
+            LengthMode::Exact => input.chars().count() == self.word_length as usize,
+            LengthMode::FreeLength => {
+                !input.is_empty() && input.to_lowercase().chars().all(|c| ALPHABET.contains(c))
+            }
+        }
+    }
+
+    fn read_line(&mut self) -> Option<String> {
+        loop {
+            let mut buffer = String::new();
+            let read = match &mut self.input {
+                Some(input) => input.read_line(&mut buffer),
+                None => stdin().read_line(&mut buffer),
+            };
+            match read {
+                Ok(0) => break None,
+                Ok(_) => break Some(buffer.trim().to_owned()),
+                Err(_) => {
+                    println!(
+                        "{}",
+                        "There was an error while reading the input, try again".red()
+                    );
+                    continue;
+                }
+            }
+        }
+    }
+
+    fn confirm(&mut self, guess: &str) -> bool {
+        println!("Submit '{}'? (y/n)", guess);
+        self.read_line()
+            .is_some_and(|answer| answer.eq_ignore_ascii_case("y"))
+    }
+
+    fn fill_known_greens(&self, typed: &str, known: &[(usize, char)]) -> String {
+        if known.is_empty() || typed.chars().count() == self.word_length as usize {
+            return typed.to_owned();
+        }
+        let mut typed = typed.chars();
+        (0..self.word_length as usize)
+            .filter_map(|i| match known.iter().find(|(pos, _)| *pos == i) {
+                Some((_, c)) => Some(*c),
+                None => typed.next(),
+            })
+            .collect()
+    }
+
+    fn get_player_word(&mut self, board: &Board) -> Option<String> {
+        let known = if self.auto_fill_greens {
+            board.correct_positions()
+        } else {
+            vec![]
+        };
+        loop {
+            if known.is_empty() {
+                println!("Insert your guess: ");
+            } else {
+                println!("Insert the missing letters: {}", template(board));
+            }
+            let line = self.read_line()?;
+            if line.trim() == MULLIGAN_COMMAND {
+                break Some(MULLIGAN_COMMAND.to_owned());
+            }
+            let guess = match self.apply_whitespace_policy(&line) {
+                Ok(guess) => self.fill_known_greens(&guess, &known),
+                Err(message) => {
+                    println!("{}", message.yellow());
+                    continue;
+                }
+            };
+            if !self.validate_input(&guess) {
+                println!("{}", "Invalid input, try again".yellow());
+                continue;
+            }
+            if self.confirm_guesses && !self.confirm(&guess) {
+                continue;
+            }
+            break Some(guess);
+        }
+    }
+}
+
+impl Player for HumanPlayer {
+    fn get_play(&mut self, board: &Board) -> Option<String> {
+        self.get_player_word(board)
+    }
+
+    fn word_length(&self) -> Option<u8> {
+        Some(self.word_length)
+    }
+
+    fn length_mode(&self) -> LengthMode {
+        self.length_mode
+    }
+}
+
+impl<P: Player + ?Sized> Player for &mut P {
+    fn get_play(&mut self, board: &Board) -> Option<String> {
+        (**self).get_play(board)
+    }
+
+    fn word_length(&self) -> Option<u8> {
+        (**self).word_length()
+    }
+
+    fn length_mode(&self) -> LengthMode {
+        (**self).length_mode()
+    }
+
+    fn prompt(&self) -> Option<String> {
+        (**self).prompt()
+    }
+
+    fn guess_accepted(&mut self) {
+        (**self).guess_accepted()
+    }
+}
+
+impl<P: Player + ?Sized> Player for Box<P> {
+    fn get_play(&mut self, board: &Board) -> Option<String> {
+        (**self).get_play(board)
+    }
+
+    fn word_length(&self) -> Option<u8> {
+        (**self).word_length()
+    }
+
+    fn length_mode(&self) -> LengthMode {
+        (**self).length_mode()
+    }
+
+    fn prompt(&self) -> Option<String> {
+        (**self).prompt()
+    }
+
+    fn guess_accepted(&mut self) {
+        (**self).guess_accepted()
+    }
+}
+
+pub struct ScriptedPlayer {
+    guesses: VecDeque<String>,
+}
+
+impl ScriptedPlayer {
+    pub fn new(guesses: &[&str]) -> Self {
+        Self {
+            guesses: guesses.iter().map(|g| g.to_string()).collect(),
+        }
+    }
+}
+
+impl Player for ScriptedPlayer {
+    fn get_play(&mut self, _: &Board) -> Option<String> {
+        self.guesses.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::consts::LETTERS_IN_WORD;
+    use super::*;
+
+    fn row(guess: &str, pattern: &str) -> Word {
+        Word::Full(
+            guess
+                .chars()
+                .zip(pattern.chars())
+                .map(|(c, p)| match p {
+                    'g' => SlotState::Match(c),
+                    'y' => SlotState::PartialMatch(c),
+                    _ => SlotState::NonMatch(c),
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_should_validate_length_ok() {
+        let game: Game<HumanPlayer> = Game::default();
+        assert!(game.player.validate_input("Tests"))
+    }
+
+    #[test]
+    fn should_not_validate_greater_length() {
+        let game: Game<HumanPlayer> = Game::default();
+        assert!(!game.player.validate_input("Hello World"))
+    }
+
+    #[test]
     fn should_not_validate_less_than() {
         let game: Game<HumanPlayer> = Game::default();
         assert!(!game.player.validate_input("Foo"))
     }
+
+    #[test]
+    fn default_game_is_coherent() {
+        let game: Game<HumanPlayer> = Game::default();
+        assert!(WORDS.contains(&game.word.as_str()));
+        assert_eq!(game.word.len(), LETTERS_IN_WORD as usize);
+        assert_eq!(game.player.word_length, LETTERS_IN_WORD);
+        assert_eq!(game.number_of_attempts, DEFAULT_ATTEMPTS);
+        assert_eq!(game.attempts_left, DEFAULT_ATTEMPTS);
+        assert_eq!(game.board.words.len(), DEFAULT_ATTEMPTS as usize);
+    }
+
+    #[test]
+    fn with_word_derives_player_length() {
+        let game = Game::with_word("test".to_owned());
+        assert_eq!(game.player.word_length, 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "player word length does not match the target word")]
+    fn should_not_construct_with_mismatched_player_length() {
+        Game::new("apple".to_owned(), DEFAULT_ATTEMPTS, HumanPlayer::new(4));
+    }
+
+    #[test]
+    fn unknown_positions_excludes_known_greens() {
+        let mut board = Board::new(5, 6);
+        board.add_word(row("spare", "xgxxx"), 0);
+        board.add_word(row("spill", "xgxgx"), 1);
+        assert_eq!(board.correct_positions(), vec![(1, 'p'), (3, 'l')]);
+        assert_eq!(board.unknown_positions(), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn starting_hint_shows_first_letter_green() {
+        colored::control::set_override(true);
+        let game = Game::with_word("apple".to_owned()).with_starting_hint(true);
+        let hint_row = game.board.hint_row(&game.renderer.theme).unwrap();
+        assert!(hint_row.contains(&"a".green().to_string()));
+        assert_eq!(game.attempts_left, DEFAULT_ATTEMPTS);
+        assert!(game.board.words.iter().all(|w| matches!(w, Word::Empty(5))));
+    }
+
+    #[test]
+    fn no_hint_row_by_default() {
+        let game = Game::with_word("apple".to_owned());
+        assert!(game.board.hint_row(&game.renderer.theme).is_none());
+    }
+
+    #[test]
+    fn valid_target_passes() {
+        assert_eq!(is_valid_target("Apple", &WordList::default()), Ok(()));
+    }
+
+    #[test]
+    fn empty_target_is_invalid() {
+        assert_eq!(
+            is_valid_target("", &WordList::default()),
+            Err(GameError::EmptyWord)
+        );
+    }
+
+    #[test]
+    fn target_outside_alphabet_is_invalid() {
+        let list: WordList = ["app1e"].into_iter().collect();
+        assert_eq!(
+            is_valid_target("app1e", &list),
+            Err(GameError::InvalidCharacter('1'))
+        );
+    }
+
+    #[test]
+    fn target_missing_from_list_is_invalid() {
+        assert_eq!(
+            is_valid_target("zzzzz", &WordList::default()),
+            Err(GameError::NotInWordList("zzzzz".to_owned()))
+        );
+    }
+
+    #[test]
+    fn try_new_rejects_mismatched_player_length() {
+        let result = Game::try_new(
+            "apple".to_owned(),
+            DEFAULT_ATTEMPTS,
+            HumanPlayer::new(4),
+            Some(WordList::default()),
+        );
+        assert!(matches!(
+            result,
+            Err(GameError::LengthMismatch {
+                expected: 4,
+                found: 5
+            })
+        ));
+    }
+
+    #[test]
+    fn reset_after_finished_game_is_playable() {
+        let player = ScriptedPlayer::new(&["crane", "apple", "crane", "spill"]);
+        let mut game = Game::new("apple".to_owned(), 3, player);
+        let summary = game.run();
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 2);
+        let capacity = game.board.words.capacity();
+
+        game.reset("crane".to_owned()).unwrap();
+        assert_eq!(game.word, "crane");
+        assert_eq!(game.attempts_left, 3);
+        assert_eq!(game.board.words.capacity(), capacity);
+        assert!(game.board.words.iter().all(|w| matches!(w, Word::Empty(5))));
+        assert!(game.run().won);
+    }
+
+    #[test]
+    fn reset_rejects_different_length() {
+        let mut game = Game::with_word("apple".to_owned());
+        assert_eq!(
+            game.reset("tests!".to_owned()),
+            Err(GameError::LengthMismatch {
+                expected: 5,
+                found: 6
+            })
+        );
+        assert_eq!(game.word, "apple");
+    }
+
+    #[test]
+    fn describe_mixed_row() {
+        assert_eq!(
+            row("apple", "gyxxy").describe(),
+            "A: correct, P: wrong spot, P: absent, L: absent, E: wrong spot"
+        );
+    }
+
+    #[test]
+    fn greens_only_hides_partial_matches() {
+        let game = Game::with_word("apple".to_owned()).with_feedback_mode(FeedbackMode::GreensOnly);
+        assert_eq!(game.get_diff("ample"), row("ample", "gxggg"));
+        assert_eq!(game.get_diff("plead"), row("plead", "xxxxx"));
+    }
+
+    #[test]
+    fn greens_only_hard_mode_can_be_won() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        let list: WordList = ["apple", "plate", "crane"].into_iter().collect();
+        let remaining = Rc::new(RefCell::new(vec![]));
+        let seen = Rc::clone(&remaining);
+        let player = ScriptedPlayer::new(&["plate", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(list)
+            .with_feedback_mode(FeedbackMode::GreensOnly)
+            .with_hard_mode(true)
+            .with_progress_callback(Box::new(move |n| seen.borrow_mut().push(n)));
+        assert!(game.run_with_output(&mut vec![]).unwrap().won);
+        assert_eq!(*remaining.borrow(), [1, 1]);
+    }
+
+    #[test]
+    fn full_feedback_shows_partial_matches() {
+        let game = Game::with_word("apple".to_owned());
+        assert_eq!(game.get_diff("plead"), row("plead", "yyyyx"));
+    }
+
+    #[test]
+    fn board_consistency_follows_feedback() {
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "crane"), 0);
+        assert!(board.is_consistent("apple"));
+        assert!(board.is_consistent("ample"));
+        assert!(!board.is_consistent("plate"));
+        assert!(!board.is_consistent("apples"));
+    }
+
+    #[test]
+    fn compact_string_encodes_filled_rows() {
+        let mut board = Board::new(5, 6);
+        board.add_word(row("apple", "gggyx"), 0);
+        board.add_word(row("crane", "xyxgx"), 1);
+        assert_eq!(board.to_compact_string(), "apple:GGGYX|crane:XYXGX");
+    }
+
+    #[test]
+    fn attempts_remaining_footer_follows_each_guess() {
+        let player = ScriptedPlayer::new(&["crane", "plate", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 4, player).with_attempts_remaining(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Attempts left: 3"));
+        assert!(out.contains("Attempts left: 2"));
+        assert!(!out.contains("Attempts left: 1"));
+    }
+
+    #[test]
+    fn no_attempts_footer_by_default() {
+        let mut game = Game::new(
+            "apple".to_owned(),
+            4,
+            ScriptedPlayer::new(&["crane", "apple"]),
+        );
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        assert!(!String::from_utf8(out).unwrap().contains("Attempts left"));
+    }
+
+    #[test]
+    fn summary_counts_letters_and_wasted_guesses() {
+        let player = ScriptedPlayer::new(&["dizzy", "crane", "gusty", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(summary.won);
+        assert_eq!(summary.wasted_guesses, 2);
+        assert_eq!(summary.distinct_letters_tried, 15);
+    }
+
+    #[test]
+    fn free_length_only_checks_alphabet() {
+        let player = HumanPlayer::new(5).with_length_mode(LengthMode::FreeLength);
+        assert!(player.validate_input("ap"));
+        assert!(player.validate_input("Apples"));
+        assert!(!player.validate_input("app1e"));
+        assert!(!player.validate_input(""));
+    }
+
+    #[test]
+    fn shorter_guess_scores_common_prefix() {
+        let game = Game::with_word("apple".to_owned());
+        assert_eq!(game.get_diff("pal"), row("pal", "yyy"));
+    }
+
+    #[test]
+    fn longer_guess_marks_extra_letters() {
+        let game = Game::with_word("apple".to_owned());
+        let mut expected = row("apple", "ggggg");
+        if let Word::Full(ref mut slots) = expected {
+            slots.push(SlotState::Extra('s'));
+        }
+        assert_eq!(game.get_diff("apples"), expected);
+        assert_eq!(expected.pattern(), "GGGGG-");
+    }
+
+    #[test]
+    fn horizontal_board_renders_on_one_line() {
+        let mut board = Board::new(5, 2);
+        board.add_word(row("crane", "xxygx"), 0);
+        let renderer = Renderer {
+            orientation: Orientation::Horizontal,
+            ..Renderer::default()
+        };
+        let rendered = board.render(&renderer);
+        assert_eq!(rendered.lines().count(), 1);
+        assert!(rendered.contains('c') && rendered.contains("#####"));
+    }
+
+    #[test]
+    fn vertical_board_renders_a_line_per_row() {
+        let board = Board::new(5, 2);
+        assert_eq!(board.render(&Renderer::default()).lines().count(), 2);
+    }
+
+    #[test]
+    fn slot_state_round_trips_through_strings() {
+        for state in [
+            SlotState::Match('a'),
+            SlotState::PartialMatch('b'),
+            SlotState::NonMatch('c'),
+            SlotState::Extra('d'),
+        ] {
+            assert_eq!(state.to_string().parse::<SlotState>(), Ok(state));
+        }
+        assert_eq!(SlotState::Match('a').to_string(), "g:a");
+        assert_eq!("y:p".parse(), Ok(SlotState::PartialMatch('p')));
+    }
+
+    #[test]
+    fn slot_state_rejects_malformed_strings() {
+        for s in ["", "g", "g:", "q:a", "g:ab", "ga"] {
+            assert_eq!(
+                s.parse::<SlotState>(),
+                Err(ParseSlotStateError(s.to_owned()))
+            );
+        }
+    }
+
+    #[test]
+    fn impossible_guess_is_warned_but_allowed() {
+        let player = ScriptedPlayer::new(&["crane", "dizzy", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_impossible_guess_warning(true);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("This word can't be the answer based on your clues"));
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 3);
+    }
+
+    #[test]
+    fn impossible_guess_is_rejected_in_hard_mode() {
+        let player = ScriptedPlayer::new(&["crane", "dizzy", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_hard_mode(true);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("try again"));
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 2);
+    }
+
+    #[test]
+    fn consistent_guess_is_not_warned() {
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_impossible_guess_warning(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        assert!(!String::from_utf8(out)
+            .unwrap()
+            .contains("can't be the answer"));
+    }
+
+    #[test]
+    fn coach_prints_a_grade_per_guess() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let player = ScriptedPlayer::new(&["plate", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(list)
+            .with_coach(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("Guess grade: ").count(), 2);
+    }
+
+    #[test]
+    fn try_new_rejects_unreachable_target() {
+        let list: WordList = ["crane", "plate"].into_iter().collect();
+        let result = Game::try_new("apple".to_owned(), 6, HumanPlayer::new(5), Some(list));
+        assert!(matches!(result, Err(GameError::TargetUnreachable(w)) if w == "apple"));
+    }
+
+    #[test]
+    fn try_new_accepts_reachable_target() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        let game = Game::try_new("Apple".to_owned(), 6, HumanPlayer::new(5), Some(list)).unwrap();
+        assert_eq!(game.target(), "apple");
+        assert!(Game::try_new("zzzzz".to_owned(), 6, HumanPlayer::new(5), None).is_ok());
+    }
+
+    #[test]
+    fn guesses_outside_word_list_are_rejected() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        let player = ScriptedPlayer::new(&["zzzzz", "crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_word_list(list);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .contains("'zzzzz' is not in the word list, try again"));
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 2);
+    }
+
+    #[test]
+    fn declined_confirmation_reprompts_without_scoring() {
+        let player = HumanPlayer::new(5)
+            .with_confirmation(true)
+            .with_input(io::Cursor::new("crane\nn\napple\ny\n"));
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 1);
+        assert_eq!(game.board.words[0].guess().as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn unconfirmed_player_submits_directly() {
+        let mut player = HumanPlayer::new(5).with_input(io::Cursor::new("toolong\ncrane\n"));
+        assert_eq!(player.get_play(&Board::new(5, 6)).as_deref(), Some("crane"));
+    }
+
+    #[test]
+    fn all_gray_guess_is_penalized() {
+        let player = ScriptedPlayer::new(&["dizzy", "crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_all_gray_penalty(3);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(game.score(), -3);
+        assert_eq!(summary.score, -3);
+    }
+
+    #[test]
+    fn informative_guesses_are_not_penalized() {
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_all_gray_penalty(3);
+        game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(game.score(), 0);
+    }
+
+    #[test]
+    fn penalty_defaults_to_zero() {
+        let player = ScriptedPlayer::new(&["dizzy", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(game.score(), 0);
+    }
+
+    #[test]
+    fn patterns_are_base_three_codes_per_row() {
+        let mut board = Board::new(5, 6);
+        board.add_word(row("apple", "ggggg"), 0);
+        board.add_word(row("crane", "xxyxg"), 1);
+        assert_eq!(board.patterns(), vec![242, 11]);
+    }
+
+    #[test]
+    fn internal_whitespace_is_rejected_by_default() {
+        let player = HumanPlayer::new(5);
+        assert!(!player.validate_input("ap pl"));
+        assert_eq!(
+            player.apply_whitespace_policy("ap ple"),
+            Err("Guesses can't contain spaces, try again")
+        );
+    }
+
+    #[test]
+    fn internal_whitespace_can_be_stripped() {
+        let mut player = HumanPlayer::new(5)
+            .with_whitespace_policy(WhitespacePolicy::Strip)
+            .with_input(io::Cursor::new("ap ple\n"));
+        assert_eq!(
+            player.apply_whitespace_policy("ap ple"),
+            Ok("apple".to_owned())
+        );
+        assert_eq!(player.get_play(&Board::new(5, 6)).as_deref(), Some("apple"));
+    }
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn from_args_parses_valid_flags() {
+        let path =
+            std::env::temp_dir().join(format!("wordle-args-list-{}.txt", std::process::id()));
+        std::fs::write(&path, "apple\ncrane\n").unwrap();
+        let game = Game::from_args(&args(&[
+            "--word",
+            "Crane",
+            "--attempts",
+            "4",
+            "--hard",
+            "--list",
+            path.to_str().unwrap(),
+        ]))
+        .unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(game.target(), "crane");
+        assert_eq!(game.number_of_attempts, 4);
+        assert!(game.hard_mode);
+        assert!(game.word_list.is_some());
+    }
+
+    #[test]
+    fn from_args_defaults_to_a_builtin_word() {
+        let game = Game::from_args(&[]).unwrap();
+        assert!(WORDS.contains(&game.target()));
+        assert_eq!(game.number_of_attempts, DEFAULT_ATTEMPTS);
+        assert!(!game.hard_mode);
+    }
+
+    #[test]
+    fn from_args_rejects_invalid_flags() {
+        assert!(matches!(
+            Game::from_args(&args(&["--colour"])),
+            Err(GameError::InvalidArgument(a)) if a == "--colour"
+        ));
+        assert!(matches!(
+            Game::from_args(&args(&["--attempts", "many"])),
+            Err(GameError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            Game::from_args(&args(&["--attempts", "0"])),
+            Err(GameError::InvalidArgument(_))
+        ));
+        assert!(matches!(
+            Game::from_args(&args(&["--word"])),
+            Err(GameError::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn color_totals_aggregate_across_rows() {
+        let mut board = Board::new(5, 6);
+        board.add_word(row("crane", "xxygx"), 0);
+        board.add_word(row("plant", "xyggx"), 1);
+        board.add_word(row("alarm", "gyxxx"), 2);
+        assert_eq!(board.total_greens(), 4);
+        assert_eq!(board.total_yellows(), 3);
+        assert_eq!(board.unique_greens(), 3);
+    }
+
+    #[test]
+    fn best_guess_distance_is_zero_on_a_win() {
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.guesses, ["crane", "apple"]);
+        assert_eq!(summary.best_guess_distance(), Some(0));
+    }
+
+    #[test]
+    fn best_guess_distance_measures_a_near_miss() {
+        let player = ScriptedPlayer::new(&["crane", "ample"]);
+        let mut game = Game::new("apple".to_owned(), 2, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(!summary.won);
+        assert_eq!(summary.best_guess_distance(), Some(1));
+    }
+
+    #[test]
+    fn summary_serializes_to_json() {
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        let json = serde_json::to_value(&summary).unwrap();
+        assert_eq!(json["won"], true);
+        assert_eq!(json["target"], "apple");
+        assert_eq!(json["attempts_used"], 2);
+        assert_eq!(json["guesses"], serde_json::json!(["crane", "apple"]));
+        assert_eq!(
+            json["grid"][0],
+            serde_json::json!(["x:c", "x:r", "y:a", "x:n", "g:e"])
+        );
+        assert_eq!(json["grid"][1][0], "g:a");
+    }
+
+    #[test]
+    fn slot_state_deserializes_from_tag() {
+        let state: SlotState = serde_json::from_str(r#""y:p""#).unwrap();
+        assert_eq!(state, SlotState::PartialMatch('p'));
+        assert!(serde_json::from_str::<SlotState>(r#""q:p""#).is_err());
+    }
+
+    #[test]
+    fn auto_fill_preserves_known_greens() {
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "spore"), 0);
+        let mut player = HumanPlayer::new(5)
+            .with_auto_fill_greens(true)
+            .with_input(io::Cursor::new("apl\nample\n"));
+        assert_eq!(player.get_play(&board).as_deref(), Some("apple"));
+        assert_eq!(player.get_play(&board).as_deref(), Some("ample"));
+    }
+
+    #[test]
+    fn auto_fill_is_off_by_default() {
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "spore"), 0);
+        let mut player = HumanPlayer::new(5).with_input(io::Cursor::new("alp\nangle\n"));
+        assert_eq!(player.get_play(&board).as_deref(), Some("angle"));
+    }
+
+    #[test]
+    fn any_acceptable_answer_wins() {
+        for guess in ["grey", "gray"] {
+            let player = ScriptedPlayer::new(&[guess]);
+            let answers = vec!["grey".to_owned(), "gray".to_owned()];
+            let mut game = Game::with_answers(answers, 6, player);
+            let summary = game.run_with_output(&mut vec![]).unwrap();
+            assert!(summary.won);
+            assert_eq!(summary.best_guess_distance(), Some(0));
+        }
+    }
+
+    #[test]
+    fn feedback_uses_the_closest_answer() {
+        let answers = vec!["colour".to_owned(), "collar".to_owned()];
+        let game = Game::with_answers(answers, 6, ScriptedPlayer::new(&[]));
+        assert_eq!(game.closest_answer("callar"), "collar");
+        assert_eq!(game.get_diff("callar"), score_guess("collar", "callar"));
+        assert_eq!(game.closest_answer("zzzzzz"), "colour");
+    }
+
+    #[test]
+    fn progress_callback_reports_shrinking_candidates() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+        let counts = Rc::new(RefCell::new(vec![]));
+        let reported = Rc::clone(&counts);
+        let player = ScriptedPlayer::new(&["crane", "plate", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(WordList::default())
+            .with_progress_callback(Box::new(move |n| reported.borrow_mut().push(n)));
+        game.run_with_output(&mut vec![]).unwrap();
+        let counts = counts.borrow();
+        assert_eq!(counts.len(), 3);
+        assert!(counts.windows(2).all(|w| w[0] >= w[1]));
+        assert_eq!(counts.last(), Some(&1));
+    }
+
+    #[test]
+    fn rgba_buffer_matches_dimensions_and_theme() {
+        let theme = Theme::default();
+        let mut board = Board::new(5, 2);
+        board.add_word(row("apple", "gxxxy"), 0);
+        let (pixels, width, height) = board.to_rgba(&theme, 4);
+        assert_eq!((width, height), (20, 8));
+        assert_eq!(pixels.len(), (width * height * 4) as usize);
+        let pixel = |x: u32, y: u32| {
+            let i = ((y * width + x) * 4) as usize;
+            [pixels[i], pixels[i + 1], pixels[i + 2]]
+        };
+        assert_eq!(pixel(0, 0), to_rgb(theme.matched));
+        assert_eq!(pixel(width - 1, 0), to_rgb(theme.partial_match));
+        assert_eq!(pixel(width - 1, height - 1), to_rgb(theme.empty));
+        assert_eq!(pixels[3], 255);
+    }
+
+    #[test]
+    fn spectator_receives_a_snapshot_per_guess() {
+        use std::sync::mpsc::channel;
+        use std::thread;
+        let (sender, receiver) = channel();
+        let handle = thread::spawn(move || {
+            let player = ScriptedPlayer::new(&["crane", "plate", "apple"]);
+            let mut game = Game::new("apple".to_owned(), 6, player).with_spectator(sender);
+            game.run_with_output(&mut vec![]).unwrap()
+        });
+        let snapshots: Vec<BoardSnapshot> = receiver.iter().collect();
+        assert!(handle.join().unwrap().won);
+        assert_eq!(snapshots.len(), 3);
+        assert_eq!(snapshots[0].words[0].guess().as_deref(), Some("crane"));
+        assert_eq!(snapshots[0].words[1], Word::Empty(5));
+        assert_eq!(snapshots[2].words[2].guess().as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn difficulty_sets_hint_budget_and_attempts() {
+        let cases = [
+            (Difficulty::Easy, 3, 8, false),
+            (Difficulty::Normal, 1, 6, false),
+            (Difficulty::Hard, 0, 6, true),
+        ];
+        for (difficulty, hints, attempts, hard_mode) in cases {
+            let game = Game::new("apple".to_owned(), 3, ScriptedPlayer::new(&[]))
+                .with_difficulty(difficulty);
+            assert_eq!(game.hints_left(), hints);
+            assert_eq!(game.number_of_attempts(), attempts);
+            assert_eq!(game.board.words.len(), attempts as usize);
+            assert_eq!(game.hard_mode, hard_mode);
+        }
+    }
+
+    #[test]
+    fn difficulty_keeps_board_settings() {
+        let mut game = Game::new("apple".to_owned(), 6, ScriptedPlayer::new(&[]))
+            .with_gray_meaning(GrayMeaning::AbsentFromUnsolved);
+        game.board.set_fixed_clue(0, SlotState::Match('a'));
+        let game = game.with_difficulty(Difficulty::Easy);
+        assert_eq!(game.board.gray_meaning, GrayMeaning::AbsentFromUnsolved);
+        assert_eq!(game.board.fixed_clues, [(0, SlotState::Match('a'))]);
+        assert_eq!(game.board.words.len(), 8);
+    }
+
+    #[test]
+    fn hints_stop_when_the_budget_runs_out() {
+        let mut game = Game::new("apple".to_owned(), 6, ScriptedPlayer::new(&[]))
+            .with_difficulty(Difficulty::Easy)
+            .with_starting_hint(true);
+        assert_eq!(game.use_hint(), Some((1, 'p')));
+        assert_eq!(game.use_hint(), Some((2, 'p')));
+        assert_eq!(game.use_hint(), Some((3, 'l')));
+        assert_eq!(game.use_hint(), None);
+        assert_eq!(game.board.hints.len(), 4);
+    }
+
+    #[test]
+    fn newly_revealed_reports_improved_letters() {
+        let mut board = Board::new(5, 6);
+        board.add_word(row("plate", "yyyxg"), 0);
+        let before = board.snapshot();
+        board.add_word(row("apple", "ggggg"), 1);
+        assert_eq!(
+            board.newly_revealed(&before),
+            vec![
+                ('a', SlotState::Match('a')),
+                ('l', SlotState::Match('l')),
+                ('p', SlotState::Match('p')),
+            ]
+        );
+        assert!(board.newly_revealed(&board.snapshot()).is_empty());
+    }
+
+    #[test]
+    fn guess_transform_is_applied_before_scoring() {
+        let player = ScriptedPlayer::new(&["r0b0t"]);
+        let mut game = Game::new("robot".to_owned(), 6, player)
+            .with_guess_transform(Box::new(|guess| guess.replace('0', "o")));
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(summary.won);
+        assert_eq!(game.board.words[0].guess().as_deref(), Some("robot"));
+    }
+
+    #[test]
+    fn common_word_countdown_counts_ranked_candidates() {
+        let list = WordList::with_ranks(
+            [
+                ("apple", 1),
+                ("angle", 2),
+                ("ample", 900),
+                ("crane", 3),
+                ("plate", 4),
+            ]
+            .into_iter()
+            .map(|(w, rank)| (w.to_owned(), rank))
+            .collect(),
+        );
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(list)
+            .with_common_word_countdown(100);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("~1 common words remaining"), "{}", out);
+    }
+
+    #[test]
+    fn forced_guess_when_one_candidate_remains() {
+        let list: WordList = ["apple", "angle", "plate", "crane"].into_iter().collect();
+        let mut board = Board::new(5, 6);
+        assert_eq!(board.forced_guess(&list), None);
+        board.add_word(score_guess("apple", "crane"), 0);
+        assert_eq!(board.forced_guess(&list).as_deref(), Some("apple"));
+    }
+
+    #[test]
+    fn fixed_clue_renders_on_the_starting_board() {
+        colored::control::set_override(true);
+        let theme = Theme::default();
+        let mut board = Board::new(5, 6);
+        board.set_fixed_clue(2, SlotState::PartialMatch('x'));
+        board.set_fixed_clue(2, SlotState::Match('a'));
+        let rendered = board.render(&Renderer::default());
+        let first_row = rendered.lines().next().unwrap();
+        let empty = "#".color(theme.empty).to_string();
+        let clue = SlotState::Match('a')
+            .to_colored_string(&theme)
+            .underline()
+            .to_string();
+        let border = "|".color(theme.border).to_string();
+        let expected = format!(
+            "{}{}{}{}{}",
+            border,
+            empty.repeat(2),
+            clue,
+            empty.repeat(2),
+            border
+        );
+        assert_eq!(first_row, expected);
+        assert_eq!(rendered.lines().count(), 7);
+    }
+
+    #[test]
+    fn accented_target_can_be_typed() {
+        let player = HumanPlayer::new(4).with_input(io::Cursor::new("café\n"));
+        assert!(player.validate_input("café"));
+        let mut game = Game::new("café".to_owned(), 6, player);
+        assert_eq!(game.board.width(), 4);
+        assert!(game.run_with_output(&mut vec![]).unwrap().won);
+    }
+
+    #[test]
+    fn closed_input_ends_the_game() {
+        let player = HumanPlayer::new(5).with_input(io::Cursor::new("crane\n"));
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(!summary.won);
+        assert_eq!(summary.guess_times.len(), 1);
+    }
+
+    #[test]
+    fn pattern_grid_strips_letters_and_extras() {
+        let player = HumanPlayer::new(5)
+            .with_length_mode(LengthMode::FreeLength)
+            .with_input(io::Cursor::new("cranes\napple\n"));
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(
+            summary.pattern_grid(),
+            vec![
+                vec![
+                    SlotState::NonMatch('_'),
+                    SlotState::NonMatch('_'),
+                    SlotState::PartialMatch('_'),
+                    SlotState::NonMatch('_'),
+                    SlotState::Match('_'),
+                ],
+                vec![SlotState::Match('_'); 5],
+            ]
+        );
+        assert_eq!(summary.pattern_grid_string(), "XXYXG\nGGGGG");
+    }
+
+    #[test]
+    fn summary_lists_anagrams_of_the_target() {
+        let list: WordList = ["least", "slate", "stale", "crane"].into_iter().collect();
+        let player = ScriptedPlayer::new(&["slate"]);
+        let mut game = Game::new("slate".to_owned(), 6, player).with_word_list(list);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.anagrams, ["least", "stale"]);
+    }
+
+    #[test]
+    fn near_miss_hints_flag_adjacent_keys() {
+        let player = ScriptedPlayer::new(&["spple", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_near_miss_hints(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("Typo-likely: s").count(), 1, "{}", out);
+    }
+
+    #[test]
+    fn information_per_guess_measures_eliminated_candidates() {
+        let list: WordList = ["apple", "angle", "plate", "crane"].into_iter().collect();
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_word_list(list);
+        let info = game
+            .run_with_output(&mut vec![])
+            .unwrap()
+            .information_per_guess();
+        assert_eq!(info.len(), 2);
+        assert!(info[0] > 0.0);
+        assert!((info[0] - 2.0).abs() < 1e-9);
+        assert_eq!(info[1], 0.0);
+    }
+
+    #[test]
+    fn mulligan_takes_back_one_guess_per_game() {
+        let player = ScriptedPlayer::new(&["crane", ":mulligan", "plate", ":mulligan", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_mulligan(true);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert_eq!(game.mulligans_used(), 1);
+        assert_eq!(summary.guesses, ["plate", "apple"]);
+        assert_eq!(summary.guess_times.len(), 2);
+        assert_eq!(game.attempts_left, 5);
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("No mulligan available, try again").count(), 1);
+    }
+
+    #[test]
+    fn human_player_passes_the_mulligan_command_through() {
+        let mut player = HumanPlayer::new(5).with_input(io::Cursor::new(":mulligan\n"));
+        assert_eq!(
+            player.get_play(&Board::new(5, 6)).as_deref(),
+            Some(MULLIGAN_COMMAND)
+        );
+    }
+
+    #[test]
+    fn reveal_orders_produce_the_same_output() {
+        let mut board = Board::new(5, 3);
+        board.set_fixed_clue(1, SlotState::Match('p'));
+        board.add_word(row("crane", "xxygx"), 0);
+        board.add_word(row("apple", "ggggg"), 1);
+        for orientation in [Orientation::Vertical, Orientation::Horizontal] {
+            let write = |reveal_order| {
+                let renderer = Renderer {
+                    orientation,
+                    reveal_order,
+                    ..Renderer::default()
+                };
+                let mut out = vec![];
+                board.write_to(&renderer, &mut out).unwrap();
+                String::from_utf8(out).unwrap()
+            };
+            let simultaneous = write(RevealOrder::Simultaneous);
+            assert_eq!(write(RevealOrder::LeftToRight), simultaneous);
+            assert!(simultaneous.contains('#'));
+        }
+    }
+
+    #[test]
+    fn summary_reports_best_similarity() {
+        let player = ScriptedPlayer::new(&["crane", "ample"]);
+        let mut game = Game::new("apple".to_owned(), 2, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!((summary.best_similarity().unwrap() - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn metadata_round_trips_with_the_summary() {
+        let mut game = Game::new("apple".to_owned(), 6, ScriptedPlayer::new(&["apple"]));
+        game.set_meta("category", "fruit");
+        game.set_meta("author", "lewis");
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        let json = serde_json::to_string(&summary).unwrap();
+        let parsed: GameSummary = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.metadata, summary.metadata);
+        assert_eq!(parsed.metadata["category"], "fruit");
+        assert_eq!(parsed.metadata.len(), 2);
+    }
+
+    #[test]
+    fn preview_rows_render_dimmed() {
+        colored::control::set_override(true);
+        let theme = Theme::default();
+        let mut board = Board::new(5, 3);
+        board.add_word(row("crane", "xxygx"), 0);
+        let preview = row("apple", "ggggg");
+        let rendered = board.render_preview(&preview, &Renderer::default());
+        let lines: Vec<&str> = rendered.lines().collect();
+        let dimmed = SlotState::Match('a')
+            .to_colored_string(&theme)
+            .dimmed()
+            .to_string();
+        let full = SlotState::PartialMatch('a')
+            .to_colored_string(&theme)
+            .to_string();
+        assert!(lines[1].contains(&dimmed));
+        assert!(lines[0].contains(&full));
+        assert!(!lines[0].contains("\x1b[2;"));
+        assert_eq!(
+            lines[2],
+            board.render(&Renderer::default()).lines().nth(2).unwrap()
+        );
+    }
+
+    #[test]
+    fn legal_guess_checks_each_rule() {
+        let mut game = Game::new("apple".to_owned(), 6, ScriptedPlayer::new(&[]))
+            .with_word_list(WordList::default())
+            .with_hard_mode(true);
+        game.board.add_word(score_guess("apple", "crane"), 0);
+        assert_eq!(
+            game.is_legal_guess("app"),
+            Err(InputError::WrongLength {
+                expected: 5,
+                found: 3
+            })
+        );
+        assert_eq!(
+            game.is_legal_guess("app1e"),
+            Err(InputError::InvalidCharacter('1'))
+        );
+        assert_eq!(
+            game.is_legal_guess("zzzzz"),
+            Err(InputError::NotInWordList("zzzzz".to_owned()))
+        );
+        assert_eq!(
+            game.is_legal_guess("plate"),
+            Err(InputError::ViolatesClues("plate".to_owned()))
+        );
+        assert_eq!(game.is_legal_guess("apple"), Ok(()));
+        assert_eq!(game.attempts_left, 6);
+    }
+
+    #[test]
+    fn run_and_is_legal_guess_share_validation() {
+        let player = ScriptedPlayer::new(&["app", "app1e", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert_eq!(summary.guesses, ["apple"]);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("guesses must have 5 letters, found 3, try again"));
+        assert!(out.contains("'1' is not part of the alphabet, try again"));
+
+        let player = HumanPlayer::new(5)
+            .with_length_mode(LengthMode::FreeLength)
+            .with_input(io::Cursor::new("pal\napple\n"));
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.guesses, ["pal", "apple"]);
+        assert_eq!(game.is_legal_guess("pal"), Ok(()));
+    }
+
+    #[test]
+    fn attempt_estimate_is_shown_after_unsolved_guesses() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(list)
+            .with_attempt_estimate(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("more guesses").count(), 1);
+        assert!(out.contains("est. 1.4 more guesses"), "{}", out);
+    }
+
+    #[test]
+    fn eliminations_are_shown_and_summarized() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let player = ScriptedPlayer::new(&["crane", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(list)
+            .with_eliminations(true);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Eliminated 3 candidates"), "{}", out);
+        assert_eq!(summary.eliminations(), [3, 1]);
+    }
+
+    #[test]
+    fn hard_mode_violations_name_the_broken_clue() {
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "crane"), 0);
+        assert_eq!(
+            hard_mode_violation(&board, "spoil"),
+            Some(HardModeViolation::MissingGreen {
+                position: 4,
+                letter: 'e'
+            })
+        );
+        assert_eq!(
+            hard_mode_violation(&board, "tithe"),
+            Some(HardModeViolation::UnusedYellow('a'))
+        );
+        assert_eq!(hard_mode_violation(&board, "apple"), None);
+    }
+
+    #[test]
+    fn eliminations_never_go_negative() {
+        // Solving the first letter loosens the gray 'a' from "banal", so
+        // "alpha" comes back into play after the second guess.
+        let list: WordList = ["banal", "axiom", "apple", "alpha"].into_iter().collect();
+        let player = ScriptedPlayer::new(&["banal", "axiom", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player)
+            .with_word_list(list)
+            .with_gray_meaning(GrayMeaning::AbsentFromUnsolved)
+            .with_eliminations(true);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.candidate_counts, [(4, 1), (1, 2), (2, 1)]);
+        assert_eq!(summary.eliminations(), [3, 0, 1]);
+        assert_eq!(summary.information_per_guess()[1], 0.0);
+    }
+
+    #[test]
+    fn correct_last_guess_is_a_win() {
+        let player = ScriptedPlayer::new(&["crane", "plate", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 3, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(summary.won);
+        assert_eq!(summary.attempts_used, 3);
+        assert_eq!(game.attempts_left, 1);
+    }
+
+    #[test]
+    fn wrong_last_guess_is_a_loss() {
+        let player = ScriptedPlayer::new(&["crane", "plate", "angle"]);
+        let mut game = Game::new("apple".to_owned(), 3, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(!summary.won);
+        assert_eq!(summary.attempts_used, 3);
+        assert_eq!(game.attempts_left, 0);
+    }
+
+    #[test]
+    fn ansi_string_contains_escape_sequences() {
+        let mut board = Board::new(5, 2);
+        board.add_word(row("crane", "xxygx"), 0);
+        let ansi = board.to_ansi_string(&Theme::default());
+        assert!(ansi.contains("\x1b[33ma\x1b[0m"), "{:?}", ansi);
+        assert!(ansi.contains("\x1b[32mn\x1b[0m"));
+        assert!(ansi.contains("\x1b[37mc\x1b[0m"));
+        assert!(ansi.starts_with("\x1b[34m|\x1b[0m"), "{:?}", ansi);
+        assert_eq!(ansi.lines().count(), 2);
+    }
+
+    #[test]
+    fn rare_feedback_is_more_surprising() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let surprise = |target: &str, guesses: &[&str]| {
+            let player = ScriptedPlayer::new(guesses);
+            let mut game = Game::new(target.to_owned(), 6, player).with_word_list(list.clone());
+            game.run_with_output(&mut vec![]).unwrap().guess_surprise()
+        };
+        let lucky = surprise("crane", &["crane"]);
+        let common = surprise("apple", &["crane", "apple"]);
+        assert!(lucky[0] > common[0], "{:?} vs {:?}", lucky, common);
+        assert!((lucky[0] - 5f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn letter_repeat_limit_rejects_guesses() {
+        let unlimited = Game::new("apple".to_owned(), 6, ScriptedPlayer::new(&[]));
+        assert_eq!(unlimited.is_legal_guess("eeeee"), Ok(()));
+
+        let player = ScriptedPlayer::new(&["eeeee", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_max_letter_repeats(2);
+        assert_eq!(
+            game.is_legal_guess("eeeee"),
+            Err(InputError::TooManyRepeats {
+                letter: 'e',
+                max: 2
+            })
+        );
+        assert_eq!(game.is_legal_guess("apple"), Ok(()));
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert_eq!(summary.guesses, ["apple"]);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("'e' can't appear more than 2 times, try again"));
+    }
+
+    #[test]
+    fn gray_meaning_changes_which_candidates_fit() {
+        let mut board = Board::new(5, 6);
+        board.add_word(row("crane", "xxxxx"), 0);
+        board.add_word(row("magic", "xxxxg"), 1);
+        assert!(!board.is_consistent("bluoc"));
+        board.set_gray_meaning(GrayMeaning::AbsentFromUnsolved);
+        assert!(board.is_consistent("bluoc"));
+        assert!(!board.is_consistent("bluco"));
+        assert!(!board.is_consistent("bloat"));
+    }
+
+    #[test]
+    fn unsolved_grays_allow_copies_colored_in_the_same_row() {
+        let mut board = Board::new(5, 6);
+        board.set_gray_meaning(GrayMeaning::AbsentFromUnsolved);
+        board.add_word(score_guess("crane", "sheep"), 0);
+        assert!(board.is_consistent("crane"));
+        assert!(!board.is_consistent("ebone"));
+        let player = ScriptedPlayer::new(&["sheep", "crane"]);
+        let mut game = Game::new("crane".to_owned(), 6, player)
+            .with_gray_meaning(GrayMeaning::AbsentFromUnsolved)
+            .with_hard_mode(true);
+        assert!(game.run_with_output(&mut vec![]).unwrap().won);
+    }
+
+    #[test]
+    fn target_hash_is_stable() {
+        let game = |word: &str| Game::new(word.to_owned(), 6, ScriptedPlayer::new(&[]));
+        assert_eq!(game("apple").target_hash(), game("APPLE").target_hash());
+        assert_eq!(game("apple").target_hash(), fnv1a(b"apple"));
+        assert_ne!(game("apple").target_hash(), game("ample").target_hash());
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn simulate_matches_the_game_diff() {
+        let pairs = [
+            ("crane", "apple"),
+            ("speed", "abide"),
+            ("eerie", "there"),
+            ("array", "rayon"),
+            ("apple", "apple"),
+        ];
+        for (guess, target) in pairs {
+            let game = Game::new(target.to_owned(), 6, ScriptedPlayer::new(&[]));
+            assert_eq!(simulate(guess, target), game.get_diff(guess), "{}", guess);
+        }
+        assert_eq!(simulate("speed", "abide"), row("speed", "xxyxy"));
+        assert_eq!(simulate("eerie", "there"), row("eerie", "yxyxg"));
+        assert_eq!(simulate("array", "rayon"), row("array", "yyxxy"));
+    }
+
+    #[test]
+    fn hearts_show_remaining_attempts() {
+        let player = ScriptedPlayer::new(&["crane", "plate", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_hearts(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let hearts: Vec<&str> = out.lines().filter(|l| l.contains('❤')).collect();
+        assert_eq!(hearts, ["❤❤❤❤❤❤", "❤❤❤❤❤🖤", "❤❤❤❤🖤🖤", "❤❤❤🖤🖤🖤"]);
+    }
+
+    #[test]
+    fn alphabet_coverage_counts_distinct_letters() {
+        let player = ScriptedPlayer::new(&["crane", "spilt", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!((summary.alphabet_coverage() - 10.0 / 26.0).abs() < 1e-9);
+    }
 }
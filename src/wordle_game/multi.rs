@@ -0,0 +1,161 @@
+use super::game_objects::{Board, Game, GameSummary, Player, SlotState, Word};
+use std::collections::BTreeSet;
+use std::io::{self, stdout, Write};
+
+pub struct MultiGame<P: Player> {
+    targets: Vec<String>,
+    number_of_attempts: u8,
+    shared_attempts: bool,
+    player: P,
+}
+
+#[derive(Debug)]
+pub struct MultiGameSummary {
+    pub boards: Vec<GameSummary>,
+    pub attempts_used: usize,
+}
+
+impl MultiGameSummary {
+    pub fn solved(&self) -> usize {
+        self.boards.iter().filter(|b| b.won).count()
+    }
+}
+
+impl<P: Player> MultiGame<P> {
+    pub fn new(targets: Vec<String>, number_of_attempts: u8, player: P) -> Self {
+        Self {
+            targets,
+            number_of_attempts,
+            shared_attempts: false,
+            player,
+        }
+    }
+
+    pub fn with_shared_attempts(mut self, shared_attempts: bool) -> Self {
+        self.shared_attempts = shared_attempts;
+        self
+    }
+
+    pub fn run(&mut self) -> MultiGameSummary {
+        self.run_with_output(&mut stdout())
+            .expect("failed to write the game to stdout")
+    }
+
+    pub fn run_with_output<W: Write>(&mut self, out: &mut W) -> io::Result<MultiGameSummary> {
+        let mut summary = MultiGameSummary {
+            boards: vec![],
+            attempts_used: 0,
+        };
+        let mut pool = self.number_of_attempts as usize;
+        for (i, target) in self.targets.iter().enumerate() {
+            let attempts = if self.shared_attempts {
+                pool
+            } else {
+                self.number_of_attempts as usize
+            };
+            if attempts == 0 {
+                break;
+            }
+            writeln!(out, "Board {} of {}", i + 1, self.targets.len())?;
+            let mut game = Game::new(target.clone(), attempts as u8, &mut self.player);
+            let board = game.run_with_output(out)?;
+            let used = board.guess_times.len();
+            summary.attempts_used += used;
+            if self.shared_attempts {
+                pool -= used;
+            }
+            summary.boards.push(board);
+        }
+        Ok(summary)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CombinedConstraints {
+    pub greens: [Vec<(usize, char)>; 2],
+    pub shared_yellows: BTreeSet<char>,
+    pub shared_grays: BTreeSet<char>,
+}
+
+fn letters_where(board: &Board, keep: fn(&SlotState) -> bool) -> BTreeSet<char> {
+    board
+        .rows()
+        .flat_map(|w| match w {
+            Word::Full(slots) => slots.as_slice(),
+            Word::Empty(_) => &[],
+        })
+        .filter(|ss| keep(ss))
+        .map(SlotState::letter)
+        .collect()
+}
+
+pub fn combine_constraints(a: &Board, b: &Board) -> CombinedConstraints {
+    let yellows = |board| letters_where(board, |ss| matches!(ss, SlotState::PartialMatch(_)));
+    let grays = |board| letters_where(board, |ss| matches!(ss, SlotState::NonMatch(_)));
+    CombinedConstraints {
+        greens: [a.correct_positions(), b.correct_positions()],
+        shared_yellows: yellows(a).intersection(&yellows(b)).copied().collect(),
+        shared_grays: grays(a).intersection(&grays(b)).copied().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::{score_guess, ScriptedPlayer};
+    use super::*;
+
+    fn targets() -> Vec<String> {
+        vec!["apple".to_owned(), "crane".to_owned(), "plate".to_owned()]
+    }
+
+    #[test]
+    fn boards_get_their_own_budget_by_default() {
+        let player = ScriptedPlayer::new(&["spill", "apple", "spill", "crane", "plate"]);
+        let mut game = MultiGame::new(targets(), 2, player);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.boards.len(), 3);
+        assert_eq!(summary.solved(), 3);
+        assert_eq!(summary.attempts_used, 5);
+    }
+
+    #[test]
+    fn shared_pool_is_consumed_across_boards() {
+        let player = ScriptedPlayer::new(&["spill", "apple", "spill", "crane"]);
+        let mut game = MultiGame::new(targets(), 4, player).with_shared_attempts(true);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.attempts_used, 4);
+        assert_eq!(summary.solved(), 2);
+        assert_eq!(summary.boards.len(), 2);
+    }
+
+    #[test]
+    fn exhausted_pool_loses_the_current_board() {
+        let player = ScriptedPlayer::new(&["apple", "spill", "trace"]);
+        let mut game = MultiGame::new(targets(), 3, player).with_shared_attempts(true);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert_eq!(summary.boards.len(), 2);
+        assert!(summary.boards[0].won);
+        assert!(!summary.boards[1].won);
+        assert_eq!(summary.attempts_used, 3);
+    }
+
+    #[test]
+    fn constraints_merge_across_boards() {
+        let mut a = Board::new(5, 6);
+        let mut b = Board::new(5, 6);
+        for (i, guess) in ["crane", "spilt"].into_iter().enumerate() {
+            a.add_word(score_guess("apple", guess), i);
+            b.add_word(score_guess("petal", guess), i);
+        }
+        let combined = combine_constraints(&a, &b);
+        assert_eq!(
+            combined.greens,
+            [vec![(1, 'p'), (3, 'l'), (4, 'e')], vec![]]
+        );
+        assert_eq!(combined.shared_yellows, BTreeSet::from(['a']));
+        assert_eq!(
+            combined.shared_grays,
+            BTreeSet::from(['c', 'i', 'n', 'r', 's'])
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use super::errors::GameError;
+use super::game_objects::{check_player_length, check_target_chars, Game, Player};
+
+impl<P: Player> Game<P> {
+    pub fn from_url(url: &str, number_of_attempts: u8, player: P) -> Result<Self, GameError> {
+        let word = fetch_word(url)?;
+        check_player_length(&word, &player)?;
+        Ok(Self::new(word, number_of_attempts, player))
+    }
+}
+
+fn fetch_word(url: &str) -> Result<String, GameError> {
+    let body = reqwest::blocking::get(url)
+        .and_then(|response| response.error_for_status())
+        .and_then(|response| response.text())
+        .map_err(|e| GameError::Network(e.to_string()))?;
+    let word = body.trim().to_lowercase();
+    check_target_chars(&word)?;
+    Ok(word)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::HumanPlayer;
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn serve_once(status: &'static str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/daily", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0; 1024];
+            let _ = stream.read(&mut request).unwrap();
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        url
+    }
+
+    #[test]
+    fn fetches_word_from_server() {
+        let url = serve_once("200 OK", "Crane\n");
+        let game = Game::from_url(&url, 6, HumanPlayer::new(5)).unwrap();
+        assert_eq!(game.target(), "crane");
+    }
+
+    #[test]
+    fn reports_server_errors() {
+        let url = serve_once("500 Internal Server Error", "");
+        let result = Game::from_url(&url, 6, HumanPlayer::new(5));
+        assert!(matches!(result, Err(GameError::Network(_))));
+    }
+
+    #[test]
+    fn rejects_length_mismatch() {
+        let url = serve_once("200 OK", "cranes");
+        let result = Game::from_url(&url, 6, HumanPlayer::new(5));
+        assert!(matches!(result, Err(GameError::LengthMismatch { .. })));
+    }
+}
@@ -0,0 +1,252 @@
+use super::game_objects::Board;
+use colored::{Color, Colorize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Theme {
+    #[serde(with = "color_name")]
+    pub matched: Color,
+    #[serde(with = "color_name")]
+    pub partial_match: Color,
+    #[serde(with = "color_name")]
+    pub non_match: Color,
+    #[serde(with = "color_name")]
+    pub empty: Color,
+    #[serde(with = "color_name")]
+    pub border: Color,
+}
+
+impl Theme {
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("themes always serialize");
+        fs::write(path, json)
+    }
+}
+
+mod color_name {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(color: &Color, serializer: S) -> Result<S::Ok, S::Error> {
+        let name = match color {
+            Color::Black => "black".to_owned(),
+            Color::Red => "red".to_owned(),
+            Color::Green => "green".to_owned(),
+            Color::Yellow => "yellow".to_owned(),
+            Color::Blue => "blue".to_owned(),
+            Color::Magenta => "magenta".to_owned(),
+            Color::Cyan => "cyan".to_owned(),
+            Color::White => "white".to_owned(),
+            Color::BrightBlack => "bright black".to_owned(),
+            Color::BrightRed => "bright red".to_owned(),
+            Color::BrightGreen => "bright green".to_owned(),
+            Color::BrightYellow => "bright yellow".to_owned(),
+            Color::BrightBlue => "bright blue".to_owned(),
+            Color::BrightMagenta => "bright magenta".to_owned(),
+            Color::BrightCyan => "bright cyan".to_owned(),
+            Color::BrightWhite => "bright white".to_owned(),
+            Color::TrueColor { r, g, b } => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        };
+        serializer.serialize_str(&name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Color, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        let parsed = match name.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 => {
+                u32::from_str_radix(hex, 16)
+                    .ok()
+                    .map(|rgb| Color::TrueColor {
+                        r: (rgb >> 16) as u8,
+                        g: (rgb >> 8) as u8,
+                        b: rgb as u8,
+                    })
+            }
+            Some(_) => None,
+            None => Color::from_str(&name).ok(),
+        };
+        parsed.ok_or_else(|| de::Error::custom(format!("'{}' is not a color", name)))
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            matched: Color::Green,
+            partial_match: Color::Yellow,
+            non_match: Color::White,
+            empty: Color::Blue,
+            border: Color::Blue,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    #[default]
+    Vertical,
+    Horizontal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RevealOrder {
+    #[default]
+    Simultaneous,
+    LeftToRight,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Renderer {
+    pub theme: Theme,
+    pub orientation: Orientation,
+    pub reveal_order: RevealOrder,
+    pub reveal_delay: Duration,
+}
+
+pub fn to_rgb(color: Color) -> [u8; 3] {
+    match color {
+        Color::Black => [0, 0, 0],
+        Color::Red => [205, 49, 49],
+        Color::Green => [106, 170, 100],
+        Color::Yellow => [201, 180, 88],
+        Color::Blue => [36, 114, 200],
+        Color::Magenta => [188, 63, 188],
+        Color::Cyan => [17, 168, 205],
+        Color::White => [120, 124, 126],
+        Color::BrightBlack => [102, 102, 102],
+        Color::BrightRed => [241, 76, 76],
+        Color::BrightGreen => [35, 209, 139],
+        Color::BrightYellow => [245, 245, 67],
+        Color::BrightBlue => [59, 142, 234],
+        Color::BrightMagenta => [214, 112, 214],
+        Color::BrightCyan => [41, 184, 219],
+        Color::BrightWhite => [229, 229, 229],
+        Color::TrueColor { r, g, b } => [r, g, b],
+    }
+}
+
+pub fn legend(theme: &Theme) -> String {
+    format!(
+        "{} correct spot  {} wrong spot  {} not in word",
+        "■".color(theme.matched),
+        "■".color(theme.partial_match),
+        "■".color(theme.non_match),
+    )
+}
+
+pub fn print_legend<W: Write>(theme: &Theme, out: &mut W) -> io::Result<()> {
+    writeln!(out, "{}", legend(theme))
+}
+
+pub fn template(board: &Board) -> String {
+    let known = board.correct_positions();
+    (0..board.width())
+        .map(|i| match known.iter().find(|(pos, _)| *pos == i) {
+            Some((_, c)) => c.to_string(),
+            None => "_".to_owned(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn styled_template(board: &Board, theme: &Theme) -> String {
+    template(board).color(theme.matched).dimmed().to_string()
+}
+
+pub fn print_template<W: Write>(board: &Board, renderer: &Renderer, out: &mut W) -> io::Result<()> {
+    writeln!(out, "{}", styled_template(board, &renderer.theme))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::score_guess;
+    use super::*;
+
+    #[test]
+    fn legend_describes_every_color() {
+        let legend = legend(&Theme::default());
+        assert!(legend.contains("correct spot"));
+        assert!(legend.contains("wrong spot"));
+        assert!(legend.contains("not in word"));
+    }
+
+    #[test]
+    fn legend_uses_theme_colors() {
+        colored::control::set_override(true);
+        let theme = Theme {
+            matched: Color::Magenta,
+            ..Theme::default()
+        };
+        assert!(legend(&theme).contains(&"■".magenta().to_string()));
+    }
+
+    #[test]
+    fn game_prints_the_legend_first() {
+        use super::super::game_objects::{Game, ScriptedPlayer};
+        let player = ScriptedPlayer::new(&["apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_legend(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let mut expected = vec![];
+        print_legend(&Theme::default(), &mut expected).unwrap();
+        assert!(out.starts_with(&expected));
+    }
+
+    #[test]
+    fn template_shows_known_greens() {
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("spore", "apple"), 0);
+        assert_eq!(template(&board), "_ p _ _ e");
+    }
+
+    #[test]
+    fn template_is_blank_without_greens() {
+        assert_eq!(template(&Board::new(5, 6)), "_ _ _ _ _");
+    }
+
+    #[test]
+    fn hard_mode_game_prints_the_template() {
+        use super::super::game_objects::{Game, ScriptedPlayer};
+        let player = ScriptedPlayer::new(&["spore", "apple"]);
+        let mut game = Game::new("apple".to_owned(), 6, player).with_hard_mode(true);
+        let mut out = vec![];
+        game.run_with_output(&mut out).unwrap();
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "spore"), 0);
+        let mut expected = vec![];
+        print_template(&board, &Renderer::default(), &mut expected).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(&String::from_utf8(expected).unwrap()));
+    }
+
+    #[test]
+    fn custom_theme_round_trips_through_a_file() {
+        let theme = Theme {
+            matched: Color::BrightGreen,
+            partial_match: Color::TrueColor {
+                r: 201,
+                g: 180,
+                b: 88,
+            },
+            non_match: Color::BrightBlack,
+            ..Theme::default()
+        };
+        let path = std::env::temp_dir().join(format!("wordle-theme-{}.json", std::process::id()));
+        theme.save(&path).unwrap();
+        let text = fs::read_to_string(&path).unwrap();
+        let loaded = Theme::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, theme);
+        assert!(text.contains("\"#c9b458\""), "{}", text);
+        assert!(text.contains("\"bright green\""));
+    }
+}
@@ -0,0 +1,64 @@
+use super::game_objects::{score_guess, Board, BoardSnapshot};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameState {
+    pub target: String,
+    pub guesses: Vec<String>,
+}
+
+pub struct Replay {
+    state: GameState,
+    board: Board,
+    index: usize,
+}
+
+impl Replay {
+    pub fn new(state: GameState) -> Self {
+        let width = state.target.chars().count() as u8;
+        let board = Board::new(width, state.guesses.len() as u8);
+        Self {
+            state,
+            board,
+            index: 0,
+        }
+    }
+}
+
+impl Iterator for Replay {
+    type Item = BoardSnapshot;
+
+    fn next(&mut self) -> Option<BoardSnapshot> {
+        let guess = self.state.guesses.get(self.index)?;
+        self.board
+            .add_word(score_guess(&self.state.target, guess), self.index);
+        self.index += 1;
+        Some(self.board.snapshot())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::Word;
+    use super::*;
+
+    #[test]
+    fn replay_reveals_one_guess_per_step() {
+        let state = GameState {
+            target: "apple".to_owned(),
+            guesses: vec!["crane".to_owned(), "plate".to_owned(), "apple".to_owned()],
+        };
+        let snapshots: Vec<BoardSnapshot> = Replay::new(state).collect();
+        assert_eq!(snapshots.len(), 3);
+        for (i, snapshot) in snapshots.iter().enumerate() {
+            let filled = snapshot
+                .words
+                .iter()
+                .filter(|w| matches!(w, Word::Full(_)))
+                .count();
+            assert_eq!(filled, i + 1);
+            assert_eq!(snapshot.words[..i], snapshots[2].words[..i]);
+        }
+        assert!(snapshots[2].words[2].is_solved());
+    }
+}
@@ -0,0 +1,67 @@
+use super::errors::GameError;
+use super::game_objects::{is_valid_target, Game};
+use super::solver::MinimaxPlayer;
+use super::word_list::WordList;
+use std::io::{self, stdout, Write};
+
+pub struct ReverseGame {
+    game: Game<MinimaxPlayer>,
+}
+
+impl ReverseGame {
+    pub fn new(
+        target: &str,
+        word_list: WordList,
+        number_of_attempts: u8,
+    ) -> Result<Self, GameError> {
+        is_valid_target(target, &word_list)?;
+        let solver = MinimaxPlayer::new(word_list.clone());
+        let game =
+            Game::new(target.to_lowercase(), number_of_attempts, solver).with_word_list(word_list);
+        Ok(Self { game })
+    }
+
+    pub fn run(&mut self) -> Option<usize> {
+        self.run_with_output(&mut stdout())
+            .expect("failed to write the game to stdout")
+    }
+
+    pub fn run_with_output<W: Write>(&mut self, out: &mut W) -> io::Result<Option<usize>> {
+        let summary = self.game.run_with_output(out)?;
+        if summary.won {
+            writeln!(
+                out,
+                "The bot found '{}' in {}",
+                summary.target, summary.attempts_used
+            )?;
+            Ok(Some(summary.attempts_used))
+        } else {
+            writeln!(out, "The bot couldn't find '{}'", summary.target)?;
+            Ok(None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solver_finds_the_chosen_word() {
+        let mut game = ReverseGame::new("Plate", WordList::default(), 6).unwrap();
+        let mut out = vec![];
+        let guesses = game.run_with_output(&mut out).unwrap().unwrap();
+        assert!(guesses <= 6);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(&format!("The bot found 'plate' in {}", guesses)));
+    }
+
+    #[test]
+    fn chosen_word_must_be_in_the_list() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        assert_eq!(
+            ReverseGame::new("plate", list, 6).err(),
+            Some(GameError::NotInWordList("plate".to_owned()))
+        );
+    }
+}
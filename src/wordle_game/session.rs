@@ -0,0 +1,272 @@
+use super::errors::GameError;
+use super::game_objects::{Game, GameSummary, Player};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io::{self, stdout, BufRead, Write};
+use std::time::{Duration, Instant};
+
+pub struct TimeAttackSession {
+    words: Vec<String>,
+    number_of_attempts: u8,
+    session_time_limit: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub struct TimeAttackResult {
+    pub rounds: usize,
+    pub solved: usize,
+    pub total_time: Duration,
+}
+
+impl TimeAttackResult {
+    pub fn words_per_minute(&self) -> f64 {
+        let minutes = self.total_time.as_secs_f64() / 60.0;
+        if minutes == 0.0 {
+            return 0.0;
+        }
+        self.solved as f64 / minutes
+    }
+}
+
+impl TimeAttackSession {
+    pub fn new(words: Vec<String>, number_of_attempts: u8) -> Self {
+        Self {
+            words,
+            number_of_attempts,
+            session_time_limit: None,
+        }
+    }
+
+    pub fn with_time_limit(mut self, limit: Duration) -> Self {
+        self.session_time_limit = Some(limit);
+        self
+    }
+
+    pub fn run<P: Player>(&self, player: P) -> Result<TimeAttackResult, GameError> {
+        self.run_with_output(player, &mut stdout())
+    }
+
+    pub fn run_with_output<P: Player, W: Write>(
+        &self,
+        player: P,
+        out: &mut W,
+    ) -> Result<TimeAttackResult, GameError> {
+        let mut result = TimeAttackResult {
+            rounds: 0,
+            solved: 0,
+            total_time: Duration::ZERO,
+        };
+        let session_started = Instant::now();
+        let mut words = self.words.iter();
+        let Some(first) = words.next() else {
+            return Ok(result);
+        };
+        let mut game = Game::new(first.clone(), self.number_of_attempts, player);
+        loop {
+            let summary = game
+                .run_with_output(out)
+                .map_err(|e| GameError::Output(e.to_string()))?;
+            result.rounds += 1;
+            result.total_time += summary.solve_time();
+            if summary.won {
+                result.solved += 1;
+            }
+            if self
+                .session_time_limit
+                .is_some_and(|limit| session_started.elapsed() >= limit)
+            {
+                break;
+            }
+            match words.next() {
+                Some(word) => game.reset(word.clone())?,
+                None => break,
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Session {
+    games: Vec<GameSummary>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionReport {
+    pub total_games: usize,
+    pub wins: usize,
+    pub win_rate: f64,
+    pub average_guesses: f64,
+    pub guess_distribution: BTreeMap<usize, usize>,
+    pub lost_words: Vec<String>,
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&mut self, summary: GameSummary) {
+        self.games.push(summary);
+    }
+
+    pub fn games(&self) -> &[GameSummary] {
+        &self.games
+    }
+
+    pub fn report(&self) -> SessionReport {
+        let won: Vec<&GameSummary> = self.games.iter().filter(|g| g.won).collect();
+        let mut guess_distribution = BTreeMap::new();
+        for game in &won {
+            *guess_distribution.entry(game.attempts_used).or_insert(0) += 1;
+        }
+        let ratio = |n: usize, d: usize| if d == 0 { 0.0 } else { n as f64 / d as f64 };
+        SessionReport {
+            total_games: self.games.len(),
+            wins: won.len(),
+            win_rate: ratio(won.len(), self.games.len()),
+            average_guesses: ratio(won.iter().map(|g| g.attempts_used).sum(), won.len()),
+            guess_distribution,
+            lost_words: self
+                .games
+                .iter()
+                .filter(|g| !g.won)
+                .map(|g| g.target.clone())
+                .collect(),
+        }
+    }
+}
+
+impl SessionReport {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("session reports always serialize")
+    }
+}
+
+impl fmt::Display for SessionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Games played: {}", self.total_games)?;
+        writeln!(f, "Win rate: {:.0}%", self.win_rate * 100.0)?;
+        writeln!(f, "Average guesses: {:.2}", self.average_guesses)?;
+        for (guesses, count) in &self.guess_distribution {
+            writeln!(f, "{}: {}", guesses, "#".repeat(*count))?;
+        }
+        if !self.lost_words.is_empty() {
+            writeln!(f, "Lost words: {}", self.lost_words.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+pub fn play_again<P, F, R, W, C>(
+    mut next_game: F,
+    answers: &mut R,
+    out: &mut W,
+    mut on_round: C,
+) -> io::Result<usize>
+where
+    P: Player,
+    F: FnMut() -> Game<P>,
+    R: BufRead,
+    W: Write,
+    C: FnMut(&GameSummary),
+{
+    let mut rounds = 0;
+    loop {
+        let summary = next_game().run_with_output(out)?;
+        rounds += 1;
+        on_round(&summary);
+        writeln!(out, "Play again? (y/n)")?;
+        let mut answer = String::new();
+        if answers.read_line(&mut answer)? == 0 || !answer.trim().eq_ignore_ascii_case("y") {
+            return Ok(rounds);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::ScriptedPlayer;
+    use super::super::stats::Stats;
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn time_attack_aggregates_rounds() {
+        let session = TimeAttackSession::new(vec!["apple".to_owned(), "crane".to_owned()], 2);
+        let player = ScriptedPlayer::new(&["apple", "spill", "trace"]);
+        let result = session.run_with_output(player, &mut vec![]).unwrap();
+        assert_eq!(result.rounds, 2);
+        assert_eq!(result.solved, 1);
+        assert!(result.total_time > Duration::ZERO);
+        let expected = 1.0 / (result.total_time.as_secs_f64() / 60.0);
+        assert!((result.words_per_minute() - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn no_rounds_yield_zero_rate() {
+        let session = TimeAttackSession::new(vec![], 2);
+        let result = session
+            .run_with_output(ScriptedPlayer::new(&[]), &mut vec![])
+            .unwrap();
+        assert_eq!(result.rounds, 0);
+        assert_eq!(result.words_per_minute(), 0.0);
+    }
+
+    #[test]
+    fn time_limit_stops_new_rounds() {
+        let words = vec!["apple".to_owned(), "crane".to_owned(), "plate".to_owned()];
+        let session = TimeAttackSession::new(words, 2).with_time_limit(Duration::from_nanos(1));
+        let player = ScriptedPlayer::new(&["apple", "crane", "plate"]);
+        let result = session.run_with_output(player, &mut vec![]).unwrap();
+        assert_eq!(result.rounds, 1);
+        assert_eq!(result.solved, 1);
+    }
+
+    #[test]
+    fn play_again_loops_until_no() {
+        let mut targets = vec!["crane", "apple"].into_iter();
+        let next_game = || {
+            let target = targets.next().expect("asked for too many rounds");
+            Game::new(target.to_owned(), 6, ScriptedPlayer::new(&[target]))
+        };
+        let mut stats = Stats::default();
+        let mut out = vec![];
+        let rounds = play_again(next_game, &mut Cursor::new("y\nn\n"), &mut out, |s| {
+            stats.record(s)
+        })
+        .unwrap();
+        assert_eq!(rounds, 2);
+        assert_eq!(stats.played, 2);
+        assert_eq!(stats.current_streak, 2);
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("Play again? (y/n)").count(), 2);
+    }
+
+    #[test]
+    fn session_report_summarizes_games() {
+        let mut session = Session::new();
+        for (target, guesses) in [
+            ("apple", &["apple"][..]),
+            ("crane", &["trace", "crane"][..]),
+            ("plate", &["spill", "trace"][..]),
+        ] {
+            let mut game = Game::new(target.to_owned(), 2, ScriptedPlayer::new(guesses));
+            session.record(game.run_with_output(&mut vec![]).unwrap());
+        }
+        let report = session.report();
+        assert_eq!(report.total_games, 3);
+        assert_eq!(report.wins, 2);
+        assert!((report.win_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(report.average_guesses, 1.5);
+        assert_eq!(report.guess_distribution, BTreeMap::from([(1, 1), (2, 1)]));
+        assert_eq!(report.lost_words, ["plate"]);
+        let text = report.to_string();
+        assert!(text.contains("Win rate: 67%"));
+        assert!(text.contains("Lost words: plate"));
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+        assert_eq!(json["lost_words"][0], "plate");
+        assert_eq!(json["guess_distribution"]["2"], 1);
+    }
+}
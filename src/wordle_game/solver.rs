@@ -0,0 +1,336 @@
+use super::game_objects::{score_guess, Board, Player};
+use super::word_list::WordList;
+use rand::seq::SliceRandom;
+use rand::{thread_rng, RngCore};
+use std::collections::{BTreeMap, HashMap};
+
+const MAX_TREE_CANDIDATES: usize = 200;
+const MAX_GUARANTEE_CANDIDATES: usize = 50;
+
+pub fn worst_case_remaining(guess: &str, candidates: &[String]) -> usize {
+    let mut buckets: HashMap<u32, usize> = HashMap::new();
+    for candidate in candidates {
+        *buckets
+            .entry(score_guess(candidate, guess).pattern_code())
+            .or_insert(0) += 1;
+    }
+    buckets.into_values().max().unwrap_or(0)
+}
+
+pub fn expected_remaining(guess: &str, candidates: &[String]) -> f64 {
+    if candidates.is_empty() {
+        return 0.0;
+    }
+    let mut buckets: HashMap<u32, usize> = HashMap::new();
+    for candidate in candidates {
+        *buckets
+            .entry(score_guess(candidate, guess).pattern_code())
+            .or_insert(0) += 1;
+    }
+    let squares: usize = buckets.values().map(|n| n * n).sum();
+    squares as f64 / candidates.len() as f64
+}
+
+// One guess to play the answer, plus one more for every sixfold cut in the
+// candidate pool, which is roughly what a decent guess achieves.
+pub fn expected_attempts(board: &Board, word_list: &WordList) -> f64 {
+    match word_list.candidates(board).count() {
+        0 => 0.0,
+        n => 1.0 + (n as f64).ln() / 6f64.ln(),
+    }
+}
+
+pub fn grade_guess(board_before: &Board, guess: &str, word_list: &WordList) -> char {
+    let candidates: Vec<String> = word_list
+        .candidates(board_before)
+        .map(str::to_owned)
+        .collect();
+    let eliminated = |g: &str| candidates.len() as f64 - expected_remaining(g, &candidates);
+    let best = word_list
+        .words()
+        .iter()
+        .map(|w| eliminated(w))
+        .fold(0.0, f64::max);
+    if best <= 0.0 {
+        return 'A';
+    }
+    match eliminated(guess) / best {
+        r if r >= 0.95 => 'A',
+        r if r >= 0.8 => 'B',
+        r if r >= 0.6 => 'C',
+        r if r >= 0.4 => 'D',
+        _ => 'F',
+    }
+}
+
+pub fn can_guarantee_solve(
+    board: &Board,
+    word_list: &WordList,
+    attempts_left: u8,
+) -> Option<String> {
+    let candidates: Vec<String> = word_list.candidates(board).map(str::to_owned).collect();
+    if candidates.len() > MAX_GUARANTEE_CANDIDATES {
+        return None;
+    }
+    candidates
+        .iter()
+        .find(|guess| guarantees_solve(guess, &candidates, attempts_left))
+        .cloned()
+}
+
+fn guarantees_solve(guess: &str, candidates: &[String], attempts_left: u8) -> bool {
+    if attempts_left == 0 {
+        return false;
+    }
+    let solved = score_guess(guess, guess).pattern_code();
+    let mut buckets: HashMap<u32, Vec<String>> = HashMap::new();
+    for candidate in candidates {
+        let code = score_guess(candidate, guess).pattern_code();
+        if code != solved {
+            buckets.entry(code).or_default().push(candidate.clone());
+        }
+    }
+    buckets.values().all(|bucket| {
+        bucket
+            .iter()
+            .any(|next| guarantees_solve(next, bucket, attempts_left - 1))
+    })
+}
+
+pub struct DecisionTree {
+    guess: String,
+    children: BTreeMap<u32, DecisionTree>,
+}
+
+impl DecisionTree {
+    pub fn guess(&self) -> &str {
+        &self.guess
+    }
+
+    pub fn recommend(&self, board: &Board) -> Option<&str> {
+        let mut node = self;
+        for row in board.rows() {
+            if row.guess()? != node.guess {
+                return None;
+            }
+            node = node.children.get(&row.pattern_code())?;
+        }
+        Some(&node.guess)
+    }
+}
+
+pub fn build_decision_tree(word_list: &WordList, max_depth: usize) -> DecisionTree {
+    build_subtree(word_list.words().to_vec(), max_depth.max(1))
+        .expect("cannot build a decision tree from an empty word list")
+}
+
+fn build_subtree(candidates: Vec<String>, depth: usize) -> Option<DecisionTree> {
+    if depth == 0 {
+        return None;
+    }
+    let pool = &candidates[..candidates.len().min(MAX_TREE_CANDIDATES)];
+    let guess = pool
+        .iter()
+        .min_by_key(|guess| worst_case_remaining(guess, &candidates))?
+        .clone();
+    let solved = score_guess(&guess, &guess).pattern_code();
+    let mut buckets: BTreeMap<u32, Vec<String>> = BTreeMap::new();
+    for candidate in candidates {
+        let code = score_guess(&candidate, &guess).pattern_code();
+        if code != solved {
+            buckets.entry(code).or_default().push(candidate);
+        }
+    }
+    let children = buckets
+        .into_iter()
+        .filter_map(|(code, bucket)| Some((code, build_subtree(bucket, depth - 1)?)))
+        .collect();
+    Some(DecisionTree { guess, children })
+}
+
+pub struct MinimaxPlayer {
+    word_list: WordList,
+}
+
+impl MinimaxPlayer {
+    pub fn new(word_list: WordList) -> Self {
+        Self { word_list }
+    }
+}
+
+impl Player for MinimaxPlayer {
+    fn get_play(&mut self, board: &Board) -> Option<String> {
+        let candidates: Vec<String> = self
+            .word_list
+            .candidates(board)
+            .map(str::to_owned)
+            .collect();
+        candidates
+            .iter()
+            .min_by_key(|guess| worst_case_remaining(guess, &candidates))
+            .cloned()
+    }
+
+    fn word_length(&self) -> Option<u8> {
+        self.word_list
+            .words()
+            .first()
+            .map(|w| w.chars().count() as u8)
+    }
+}
+
+pub struct RandomPlayer {
+    word_list: WordList,
+    rng: Box<dyn RngCore>,
+}
+
+impl RandomPlayer {
+    pub fn new(word_list: WordList) -> Self {
+        Self {
+            word_list,
+            rng: Box::new(thread_rng()),
+        }
+    }
+
+    pub fn with_rng(mut self, rng: Box<dyn RngCore>) -> Self {
+        self.rng = rng;
+        self
+    }
+}
+
+impl Player for RandomPlayer {
+    fn get_play(&mut self, board: &Board) -> Option<String> {
+        let mut candidates: Vec<&str> = self.word_list.candidates(board).collect();
+        candidates.sort_unstable();
+        candidates.choose(&mut self.rng).map(|w| w.to_string())
+    }
+
+    fn word_length(&self) -> Option<u8> {
+        self.word_list
+            .words()
+            .first()
+            .map(|w| w.chars().count() as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::Game;
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn words(words: &[&str]) -> Vec<String> {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn worst_case_is_largest_bucket() {
+        let candidates = words(&["apple", "ample", "angle", "crane"]);
+        assert_eq!(worst_case_remaining("crane", &candidates), 2);
+        assert_eq!(worst_case_remaining("zzzzz", &candidates), 4);
+        assert_eq!(worst_case_remaining("apple", &[]), 0);
+    }
+
+    #[test]
+    fn more_candidates_need_more_attempts() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let mut board = Board::new(5, 6);
+        let open = expected_attempts(&board, &list);
+        board.add_word(score_guess("apple", "crane"), 0);
+        let narrowed = expected_attempts(&board, &list);
+        assert!(open > narrowed, "{} should exceed {}", open, narrowed);
+        board.add_word(score_guess("apple", "ample"), 1);
+        assert_eq!(expected_attempts(&board, &list), 1.0);
+    }
+
+    #[test]
+    fn informative_guess_grades_higher() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate", "trace"]
+            .into_iter()
+            .collect();
+        let board = Board::new(5, 6);
+        let great = grade_guess(&board, "plate", &list);
+        let wasteful = grade_guess(&board, "fuzzy", &list);
+        assert!(great < wasteful, "{} should beat {}", great, wasteful);
+        assert_eq!(wasteful, 'F');
+    }
+
+    #[test]
+    fn minimax_player_solves_small_list() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let player = MinimaxPlayer::new(list);
+        let mut game = Game::new("plate".to_owned(), 6, player);
+        assert!(game.run_with_output(&mut vec![]).unwrap().won);
+    }
+
+    #[test]
+    fn minimax_player_gives_up_without_candidates() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("zzzzz", "zzzzz"), 0);
+        assert_eq!(MinimaxPlayer::new(list).get_play(&board), None);
+    }
+
+    #[test]
+    fn seeded_random_player_is_reproducible() {
+        let play = |seed| {
+            let player = RandomPlayer::new(WordList::default())
+                .with_rng(Box::new(StdRng::seed_from_u64(seed)));
+            let mut game = Game::new("crane".to_owned(), 6, player);
+            game.run_with_output(&mut vec![]).unwrap().guesses
+        };
+        assert_eq!(play(3), play(3));
+    }
+
+    #[test]
+    fn random_player_gives_up_without_candidates() {
+        let list: WordList = ["apple", "crane"].into_iter().collect();
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("zzzzz", "zzzzz"), 0);
+        assert_eq!(RandomPlayer::new(list).get_play(&board), None);
+    }
+
+    #[test]
+    fn decision_tree_follows_feedback() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let tree = build_decision_tree(&list, 3);
+        let mut board = Board::new(5, 6);
+        let mut minimax = MinimaxPlayer::new(list.clone());
+        let root = tree.recommend(&board).unwrap().to_owned();
+        assert_eq!(Some(root.as_str()), minimax.get_play(&board).as_deref());
+
+        let target = list.words().iter().find(|w| **w != root).unwrap();
+        board.add_word(score_guess(target, &root), 0);
+        let next = tree.recommend(&board).unwrap();
+        assert_eq!(Some(next), minimax.get_play(&board).as_deref());
+        assert!(board.is_consistent(next));
+
+        let shallow = build_decision_tree(&list, 1);
+        assert_eq!(shallow.guess(), root);
+        assert_eq!(shallow.recommend(&board), None);
+    }
+
+    #[test]
+    fn finds_a_guess_that_guarantees_the_solve() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let board = Board::new(5, 6);
+        assert_eq!(can_guarantee_solve(&board, &list, 1), None);
+        let guess = can_guarantee_solve(&board, &list, 3).unwrap();
+        let candidates: Vec<String> = list.words().to_vec();
+        assert!(guarantees_solve(&guess, &candidates, 3));
+        for target in list.words() {
+            let mut board = Board::new(5, 6);
+            board.add_word(score_guess(target, &guess), 0);
+            assert!(can_guarantee_solve(&board, &list, 2).is_some());
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use super::game_objects::GameSummary;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub played: u32,
+    pub won: u32,
+    pub current_streak: u32,
+    pub max_streak: u32,
+    pub guess_distribution: BTreeMap<usize, u32>,
+}
+
+impl Stats {
+    pub fn record(&mut self, summary: &GameSummary) {
+        self.played += 1;
+        if summary.won {
+            self.won += 1;
+            self.current_streak += 1;
+            self.max_streak = self.max_streak.max(self.current_streak);
+            *self
+                .guess_distribution
+                .entry(summary.attempts_used)
+                .or_insert(0) += 1;
+        } else {
+            self.current_streak = 0;
+        }
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn load_or_default<P: AsRef<Path>>(path: P) -> Self {
+        Self::load(path).unwrap_or_default()
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).expect("stats always serialize");
+        fs::write(path, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::{Game, ScriptedPlayer};
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn saved_stats_file_loads_back() {
+        let mut stats = Stats::default();
+        for guesses in [&["crane", "apple"][..], &["crane"; 6][..]] {
+            let mut game = Game::new("apple".to_owned(), 6, ScriptedPlayer::new(guesses));
+            stats.record(&game.run_with_output(&mut vec![]).unwrap());
+        }
+        let path = env::temp_dir().join(format!("wordle-stats-{}.json", std::process::id()));
+        stats.save(&path).unwrap();
+        let json: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        let loaded = Stats::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(json["played"], 2);
+        assert_eq!(loaded, stats);
+        assert_eq!(loaded.won, 1);
+        assert_eq!(loaded.current_streak, 0);
+        assert_eq!(loaded.guess_distribution.get(&2), Some(&1));
+    }
+}
@@ -0,0 +1,117 @@
+use super::game_objects::{Board, Game, GameSummary, LengthMode, Player};
+use std::io::{self, stdout, Write};
+
+pub struct TurnBasedGame<P: Player> {
+    game: Game<Turns<P>>,
+}
+
+struct Turns<P: Player> {
+    players: Vec<(String, P)>,
+    active: usize,
+    last: usize,
+}
+
+impl<P: Player> Player for Turns<P> {
+    fn get_play(&mut self, board: &Board) -> Option<String> {
+        self.players[self.active].1.get_play(board)
+    }
+
+    fn prompt(&self) -> Option<String> {
+        Some(format!("{}'s turn", self.players[self.active].0))
+    }
+
+    fn guess_accepted(&mut self) {
+        self.players[self.active].1.guess_accepted();
+        self.last = self.active;
+        self.active = (self.active + 1) % self.players.len();
+    }
+
+    fn word_length(&self) -> Option<u8> {
+        self.players.iter().find_map(|(_, p)| p.word_length())
+    }
+
+    fn length_mode(&self) -> LengthMode {
+        self.players[self.active].1.length_mode()
+    }
+}
+
+impl<P: Player> TurnBasedGame<P> {
+    pub fn new(word: String, number_of_attempts: u8, players: Vec<(String, P)>) -> Self {
+        assert!(!players.is_empty(), "a turn based game needs players");
+        let turns = Turns {
+            players,
+            active: 0,
+            last: 0,
+        };
+        Self {
+            game: Game::new(word, number_of_attempts, turns),
+        }
+    }
+
+    pub fn active_player(&self) -> &str {
+        let turns = self.game.player();
+        &turns.players[turns.active].0
+    }
+
+    pub fn run(&mut self) -> GameSummary {
+        self.run_with_output(&mut stdout())
+            .expect("failed to write the game to stdout")
+    }
+
+    pub fn run_with_output<W: Write>(&mut self, out: &mut W) -> io::Result<GameSummary> {
+        let mut summary = self.game.run_with_output(out)?;
+        if summary.won {
+            let turns = self.game.player();
+            summary.solved_by = Some(turns.players[turns.last].0.clone());
+        }
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::ScriptedPlayer;
+    use super::*;
+
+    #[test]
+    fn second_player_lands_the_solve() {
+        let players = vec![
+            ("ana".to_owned(), ScriptedPlayer::new(&["crane", "plate"])),
+            ("ben".to_owned(), ScriptedPlayer::new(&["spill", "apple"])),
+        ];
+        let mut game = TurnBasedGame::new("apple".to_owned(), 6, players);
+        assert_eq!(game.active_player(), "ana");
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(summary.won);
+        assert_eq!(summary.guess_times.len(), 4);
+        assert_eq!(summary.solved_by.as_deref(), Some("ben"));
+    }
+
+    #[test]
+    fn rejected_guess_keeps_the_turn() {
+        let players = vec![
+            ("ana".to_owned(), ScriptedPlayer::new(&["app", "crane"])),
+            ("ben".to_owned(), ScriptedPlayer::new(&["apple"])),
+        ];
+        let mut game = TurnBasedGame::new("apple".to_owned(), 6, players);
+        let mut out = vec![];
+        let summary = game.run_with_output(&mut out).unwrap();
+        assert_eq!(summary.guesses, ["crane", "apple"]);
+        assert_eq!(summary.solved_by.as_deref(), Some("ben"));
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("ana's turn").count(), 2);
+        assert_eq!(out.matches("ben's turn").count(), 1);
+    }
+
+    #[test]
+    fn nobody_solves_a_lost_game() {
+        let players = vec![
+            ("ana".to_owned(), ScriptedPlayer::new(&["crane"])),
+            ("ben".to_owned(), ScriptedPlayer::new(&["spill"])),
+        ];
+        let mut game = TurnBasedGame::new("apple".to_owned(), 2, players);
+        let summary = game.run_with_output(&mut vec![]).unwrap();
+        assert!(!summary.won);
+        assert_eq!(summary.solved_by, None);
+    }
+}
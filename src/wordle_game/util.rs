@@ -0,0 +1,92 @@
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current.push(substitution.min(insertion).min(deletion));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+pub fn word_similarity(a: &str, b: &str) -> f64 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    let positional = a.iter().zip(&b).filter(|(x, y)| x == y).count();
+    let mut remaining = b.clone();
+    let shared = a
+        .iter()
+        .filter(|c| match remaining.iter().position(|r| r == *c) {
+            Some(i) => {
+                remaining.swap_remove(i);
+                true
+            }
+            None => false,
+        })
+        .count();
+    (positional + shared) as f64 / (2 * longest) as f64
+}
+
+const KEYBOARD_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+fn key_position(c: char) -> Option<(i32, i32)> {
+    let c = c.to_ascii_lowercase();
+    KEYBOARD_ROWS.iter().enumerate().find_map(|(row, keys)| {
+        keys.find(c)
+            .map(|col| (row as i32, 2 * col as i32 + row as i32))
+    })
+}
+
+pub fn keyboard_adjacent(a: char, b: char) -> bool {
+    match (key_position(a), key_position(b)) {
+        (Some((ra, xa)), Some((rb, xb))) => match (ra - rb).abs() {
+            0 => (xa - xb).abs() == 2,
+            1 => (xa - xb).abs() == 1,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levenshtein_counts_edits() {
+        assert_eq!(levenshtein("apple", "apple"), 0);
+        assert_eq!(levenshtein("apple", "ample"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+    }
+
+    #[test]
+    fn similarity_blends_positional_and_shared_letters() {
+        assert!((word_similarity("apple", "ample") - 0.8).abs() < 1e-9);
+        assert!((word_similarity("least", "slate") - 0.6).abs() < 1e-9);
+        assert_eq!(word_similarity("apple", "apple"), 1.0);
+        assert_eq!(word_similarity("crane", "fuzzy"), 0.0);
+    }
+
+    #[test]
+    fn keyboard_adjacency_follows_qwerty() {
+        assert!(keyboard_adjacent('s', 'a'));
+        assert!(keyboard_adjacent('S', 'w'));
+        assert!(keyboard_adjacent('s', 'z'));
+        assert!(keyboard_adjacent('g', 'b'));
+        assert!(!keyboard_adjacent('s', 'p'));
+        assert!(!keyboard_adjacent('s', 's'));
+        assert!(!keyboard_adjacent('q', 'z'));
+        assert!(!keyboard_adjacent('1', 'q'));
+    }
+}
@@ -0,0 +1,442 @@
+use super::consts::WORDS;
+use super::errors::GameError;
+use super::game_objects::Board;
+use rand::seq::SliceRandom;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordList {
+    words: Vec<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    ranks: BTreeMap<String, u32>,
+}
+
+impl WordList {
+    pub fn new(words: Vec<String>) -> Self {
+        Self {
+            words: words.into_iter().map(|w| w.to_lowercase()).collect(),
+            ranks: BTreeMap::new(),
+        }
+    }
+
+    pub fn with_ranks(ranked: Vec<(String, u32)>) -> Self {
+        let ranked: Vec<(String, u32)> = ranked
+            .into_iter()
+            .map(|(w, rank)| (w.to_lowercase(), rank))
+            .collect();
+        Self {
+            words: ranked.iter().map(|(w, _)| w.clone()).collect(),
+            ranks: ranked.into_iter().collect(),
+        }
+    }
+
+    pub fn from_text(text: &str) -> Self {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect()
+    }
+
+    pub fn from_js_array(text: &str) -> Result<Self, GameError> {
+        let malformed = |why: &str| GameError::WordListFile(format!("malformed JS array: {}", why));
+        let inner = text
+            .trim()
+            .trim_end_matches(';')
+            .trim_end()
+            .strip_prefix('[')
+            .and_then(|rest| rest.strip_suffix(']'))
+            .ok_or_else(|| malformed("expected [ ... ]"))?;
+        let mut items: Vec<&str> = inner.split(',').map(str::trim).collect();
+        if items.last() == Some(&"") {
+            items.pop();
+        }
+        items
+            .into_iter()
+            .map(|item| {
+                let quote = item
+                    .chars()
+                    .next()
+                    .filter(|q| *q == '"' || *q == '\'')
+                    .ok_or_else(|| malformed(&format!("{} is not a string", item)))?;
+                item[1..]
+                    .strip_suffix(quote)
+                    .filter(|word| !word.is_empty() && !word.contains(quote))
+                    .map(str::to_owned)
+                    .ok_or_else(|| malformed(&format!("{} is not a string", item)))
+            })
+            .collect()
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Ok(Self::from_text(&fs::read_to_string(path)?))
+    }
+
+    fn sorted_words(&self) -> Vec<&str> {
+        let mut words: Vec<&str> = self.words.iter().map(String::as_str).collect();
+        words.sort_unstable();
+        words
+    }
+
+    pub fn random_word(&self, rng: &mut dyn RngCore) -> Option<&str> {
+        self.sorted_words().choose(rng).copied()
+    }
+
+    pub fn length_histogram(&self) -> BTreeMap<usize, usize> {
+        let mut histogram = BTreeMap::new();
+        for word in &self.words {
+            *histogram.entry(word.chars().count()).or_insert(0) += 1;
+        }
+        histogram
+    }
+
+    pub fn supports_length(&self, length: usize, min_count: usize) -> bool {
+        self.length_histogram().get(&length).copied().unwrap_or(0) >= min_count
+    }
+
+    pub fn shuffled(&self, rng: &mut dyn RngCore) -> Vec<String> {
+        let mut words: Vec<String> = self.sorted_words().into_iter().map(str::to_owned).collect();
+        words.shuffle(rng);
+        words
+    }
+
+    pub fn anagrams_of(&self, word: &str) -> Vec<&str> {
+        let sorted = |w: &str| {
+            let mut letters: Vec<char> = w.to_lowercase().chars().collect();
+            letters.sort_unstable();
+            letters
+        };
+        let key = sorted(word);
+        let word = word.to_lowercase();
+        self.words
+            .iter()
+            .filter(|w| **w != word && sorted(w) == key)
+            .map(String::as_str)
+            .collect()
+    }
+
+    pub fn random_weighted(
+        &self,
+        length: usize,
+        difficulty_bias: f64,
+        rng: &mut dyn RngCore,
+    ) -> Option<&str> {
+        let unranked = self.ranks.values().max().map_or(1, |max| max + 1);
+        let mut words = self.sorted_words();
+        words.retain(|w| w.chars().count() == length);
+        words
+            .choose_weighted(rng, |w| {
+                let rank = self.ranks.get(*w).copied().unwrap_or(unranked).max(1);
+                (rank as f64).powf(-difficulty_bias)
+            })
+            .ok()
+            .copied()
+    }
+
+    pub fn random_consistent(&self, board: &Board, rng: &mut dyn RngCore) -> Option<String> {
+        let mut candidates: Vec<&str> = self.candidates(board).collect();
+        candidates.sort_unstable();
+        candidates.choose(rng).map(|w| w.to_string())
+    }
+
+    pub fn rank(&self, word: &str) -> Option<u32> {
+        self.ranks.get(&word.to_lowercase()).copied()
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("word lists always serialize")
+    }
+
+    pub fn from_json(s: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(s)
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        self.words.contains(&word)
+    }
+
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    pub fn candidates<'a>(&'a self, board: &'a Board) -> impl Iterator<Item = &'a str> + 'a {
+        self.words
+            .iter()
+            .map(String::as_str)
+            .filter(move |w| board.is_consistent(w))
+    }
+
+    pub fn letter_frequencies(&self) -> HashMap<char, usize> {
+        let mut frequencies = HashMap::new();
+        for c in self.words.iter().flat_map(|w| w.chars()) {
+            *frequencies.entry(c).or_insert(0) += 1;
+        }
+        frequencies
+    }
+
+    pub fn positional_frequencies(&self) -> Vec<HashMap<char, usize>> {
+        let mut frequencies: Vec<HashMap<char, usize>> = vec![];
+        for w in &self.words {
+            for (i, c) in w.chars().enumerate() {
+                if frequencies.len() <= i {
+                    frequencies.push(HashMap::new());
+                }
+                *frequencies[i].entry(c).or_insert(0) += 1;
+            }
+        }
+        frequencies
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+}
+
+impl Default for WordList {
+    fn default() -> Self {
+        Self::new(WORDS.iter().map(|w| w.to_string()).collect())
+    }
+}
+
+impl<S: Into<String>> FromIterator<S> for WordList {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        Self::new(iter.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::game_objects::score_guess;
+    use super::*;
+
+    #[test]
+    fn contains_is_case_insensitive() {
+        let list: WordList = ["apple", "Crane"].into_iter().collect();
+        assert!(list.contains("APPLE"));
+        assert!(list.contains("crane"));
+        assert!(!list.contains("trace"));
+    }
+
+    #[test]
+    fn default_list_uses_builtin_words() {
+        assert_eq!(WordList::default().len(), WORDS.len());
+    }
+
+    #[test]
+    fn candidates_respect_board_constraints() {
+        let list: WordList = ["apple", "ample", "angle", "crane", "plate"]
+            .into_iter()
+            .collect();
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "crane"), 0);
+        let candidates: Vec<&str> = list.candidates(&board).collect();
+        assert_eq!(candidates, vec!["apple", "ample"]);
+    }
+
+    #[test]
+    fn letter_frequencies_count_every_occurrence() {
+        let list: WordList = ["apple", "crane", "plate"].into_iter().collect();
+        let frequencies = list.letter_frequencies();
+        assert_eq!(frequencies[&'p'], 3);
+        assert_eq!(frequencies[&'e'], 3);
+        assert_eq!(frequencies[&'c'], 1);
+        assert!(!frequencies.contains_key(&'z'));
+    }
+
+    #[test]
+    fn positional_frequencies_count_per_column() {
+        let list: WordList = ["apple", "crane", "plate"].into_iter().collect();
+        let frequencies = list.positional_frequencies();
+        assert_eq!(frequencies.len(), 5);
+        assert_eq!(frequencies[0][&'a'], 1);
+        assert_eq!(frequencies[2][&'a'], 2);
+        assert_eq!(frequencies[4][&'e'], 3);
+        assert!(!frequencies[0].contains_key(&'e'));
+    }
+
+    #[test]
+    fn ranked_list_round_trips_through_json() {
+        let list = WordList::with_ranks(vec![("crane".to_owned(), 2), ("Apple".to_owned(), 1)]);
+        let json = list.to_json();
+        let parsed = WordList::from_json(&json).unwrap();
+        assert_eq!(parsed, list);
+        assert_eq!(parsed.rank("apple"), Some(1));
+        assert_eq!(parsed.words(), ["crane", "apple"]);
+    }
+
+    #[test]
+    fn unranked_list_omits_ranks() {
+        let list: WordList = ["apple"].into_iter().collect();
+        assert_eq!(list.to_json(), r#"{"words":["apple"]}"#);
+        assert_eq!(WordList::from_json(&list.to_json()).unwrap(), list);
+        assert!(WordList::from_json("[1, 2]").is_err());
+    }
+
+    #[test]
+    fn from_text_reads_one_word_per_line() {
+        let list = WordList::from_text("Apple\n  crane \n\nplate\n");
+        assert_eq!(list.words(), ["apple", "crane", "plate"]);
+        let mut rng = rand::thread_rng();
+        assert!(list.contains(list.random_word(&mut rng).unwrap()));
+        assert_eq!(WordList::from_text("").random_word(&mut rng), None);
+    }
+
+    #[test]
+    fn seeded_random_word_is_reproducible() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        let list = WordList::default();
+        let picks = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..5)
+                .map(|_| list.random_word(&mut rng).unwrap().to_owned())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(picks(7), picks(7));
+    }
+
+    #[test]
+    fn random_consistent_word_passes_the_board() {
+        let list = WordList::default();
+        let mut board = Board::new(5, 6);
+        board.add_word(score_guess("apple", "crane"), 0);
+        let mut rng = rand::thread_rng();
+        for _ in 0..10 {
+            let word = list.random_consistent(&board, &mut rng).unwrap();
+            assert!(board.is_consistent(&word));
+        }
+        board.add_word(score_guess("apple", "zzzzz"), 1);
+        board.add_word(score_guess("zzzzz", "apple"), 2);
+        assert_eq!(list.random_consistent(&board, &mut rng), None);
+    }
+
+    #[test]
+    fn easy_bias_prefers_common_words() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let list = WordList::with_ranks(
+            WORDS
+                .iter()
+                .take(100)
+                .enumerate()
+                .map(|(i, w)| (w.to_string(), i as u32 + 1))
+                .collect(),
+        );
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut common_picks = |bias| {
+            (0..1000)
+                .filter(|_| {
+                    let word = list.random_weighted(5, bias, &mut rng).unwrap();
+                    list.rank(word).unwrap() <= 10
+                })
+                .count()
+        };
+        assert!(common_picks(3.0) > 900);
+        assert!(common_picks(0.0) < 200);
+        assert!(common_picks(-3.0) < 10);
+        assert_eq!(list.random_weighted(6, 3.0, &mut rng), None);
+    }
+
+    #[test]
+    fn anagrams_share_the_letter_multiset() {
+        let list: WordList = [
+            "least", "slate", "stale", "steal", "tales", "stall", "apple",
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(
+            list.anagrams_of("Steal"),
+            ["least", "slate", "stale", "tales"]
+        );
+        assert!(list.anagrams_of("apple").is_empty());
+    }
+
+    #[test]
+    fn seeded_shuffle_is_a_reproducible_permutation() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let list = WordList::default();
+        let first = list.shuffled(&mut StdRng::seed_from_u64(11));
+        assert_eq!(first, list.shuffled(&mut StdRng::seed_from_u64(11)));
+        assert_ne!(first, list.words());
+        let mut sorted = first.clone();
+        sorted.sort();
+        let mut expected = list.words().to_vec();
+        expected.sort();
+        assert_eq!(sorted, expected);
+    }
+
+    #[test]
+    fn histogram_counts_words_per_length() {
+        let list: WordList = ["cat", "dog", "apple", "crane", "plate", "planet"]
+            .into_iter()
+            .collect();
+        let histogram = list.length_histogram();
+        assert_eq!(histogram, BTreeMap::from([(3, 2), (5, 3), (6, 1)]));
+        assert!(list.supports_length(5, 3));
+        assert!(!list.supports_length(6, 2));
+        assert!(!list.supports_length(4, 1));
+        assert!(list.supports_length(4, 0));
+    }
+
+    #[test]
+    fn parses_js_array_word_lists() {
+        let list = WordList::from_js_array("  [\"aback\", 'Abase' ,\n  \"abate\",\n]; ").unwrap();
+        assert_eq!(list.words(), ["aback", "abase", "abate"]);
+        assert!(WordList::from_js_array("[]").unwrap().is_empty());
+        for malformed in [
+            "\"aback\"",
+            "[aback]",
+            "[\"aback\", , \"abase\"]",
+            "[\"aback']",
+        ] {
+            assert!(
+                matches!(
+                    WordList::from_js_array(malformed),
+                    Err(GameError::WordListFile(_))
+                ),
+                "{}",
+                malformed
+            );
+        }
+    }
+
+    #[test]
+    fn seeded_selection_ignores_insertion_order() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let forward = WordList::default();
+        let mut words = forward.words().to_vec();
+        words.reverse();
+        let backward = WordList::new(words);
+        let board = Board::new(5, 6);
+        for seed in 0..20 {
+            let rng = || StdRng::seed_from_u64(seed);
+            assert_eq!(
+                forward.random_word(&mut rng()),
+                backward.random_word(&mut rng())
+            );
+            assert_eq!(
+                forward.random_consistent(&board, &mut rng()),
+                backward.random_consistent(&board, &mut rng())
+            );
+            assert_eq!(
+                forward.random_weighted(5, 1.0, &mut rng()),
+                backward.random_weighted(5, 1.0, &mut rng())
+            );
+            assert_eq!(forward.shuffled(&mut rng()), backward.shuffled(&mut rng()));
+        }
+    }
+}